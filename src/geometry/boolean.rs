@@ -0,0 +1,260 @@
+use std::collections::HashSet;
+
+use crate::shapes::{path::Path, point::Point, shape::Shape};
+
+use super::polygon::{
+    assemble, edges, key, link, normalize, outline, split_edges, winding_number, Edge, Key,
+};
+
+/// The different ways two closed shapes can be combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOperation {
+    /// Everything that is inside either shape
+    Union,
+
+    /// Everything that is inside both shapes
+    Intersection,
+
+    /// Everything that is inside the first shape but not the second
+    Difference,
+
+    /// Everything that is inside exactly one of the shapes
+    Xor,
+}
+
+/**
+Combine two closed shapes into new shapes. Each resulting [`Path`] is one solid region,
+with any holes in it added as extra rings, see [`Path::from_rings`]. The results get the
+style of {a} as a polygon.
+
+Any shape can be combined, it is turned into a polygon with [`Shape::to_polygon`] first,
+so curves, like the outline of a circle or a curved path, are flattened.
+
+Example
+```
+use generative_art::{
+    geometry::boolean::{boolean, BooleanOperation},
+    shapes::{circle::Circle, point::Point, rectangle::Rectangle},
+};
+
+let square = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+let hole = Circle::new(Point(50.0, 50.0), 20.0);
+
+let stencil = boolean(&square, &hole, BooleanOperation::Difference);
+assert_eq!(stencil.len(), 1);
+assert_eq!(stencil[0].rings().len(), 2);
+```
+*/
+pub fn boolean(a: &dyn Shape, b: &dyn Shape, operation: BooleanOperation) -> Vec<Path> {
+    let (a, b) = (a.to_polygon(0.1), b.to_polygon(0.1));
+
+    if operation == BooleanOperation::Xor {
+        let mut paths = boolean(&a, &b, BooleanOperation::Difference);
+        paths.extend(
+            boolean(&b, &a, BooleanOperation::Difference)
+                .into_iter()
+                .map(|path| Path::from_rings(path.rings(), a.style)),
+        );
+        return paths;
+    }
+
+    let rings_a = normalize(&a);
+    let rings_b = normalize(&b);
+    let (edges_a, edges_b) = split_edges(&edges(&rings_a), &edges(&rings_b));
+
+    let keys_a: HashSet<(Key, Key)> = edges_a
+        .iter()
+        .map(|(from, to)| (key(from), key(to)))
+        .collect();
+    let keys_b: HashSet<(Key, Key)> = edges_b
+        .iter()
+        .map(|(from, to)| (key(from), key(to)))
+        .collect();
+
+    let mut selected: Vec<Edge> = vec![];
+
+    for (from, to) in edges_a {
+        let same = keys_b.contains(&(key(&from), key(&to)));
+        let opposite = keys_b.contains(&(key(&to), key(&from)));
+        let inside = winding_number(&rings_b, &from.between(&to, 0.5)) != 0;
+
+        let keep = match operation {
+            _ if same => operation != BooleanOperation::Difference,
+            _ if opposite => operation == BooleanOperation::Difference,
+            BooleanOperation::Intersection => inside,
+            _ => !inside,
+        };
+
+        if keep {
+            selected.push((from, to));
+        }
+    }
+
+    for (from, to) in edges_b {
+        if keys_a.contains(&(key(&from), key(&to))) || keys_a.contains(&(key(&to), key(&from))) {
+            continue;
+        }
+
+        let inside = winding_number(&rings_a, &from.between(&to, 0.5)) != 0;
+
+        match operation {
+            BooleanOperation::Union if !inside => selected.push((from, to)),
+            BooleanOperation::Intersection if inside => selected.push((from, to)),
+            BooleanOperation::Difference if inside => selected.push((to, from)),
+            _ => {}
+        }
+    }
+
+    assemble(link(selected), a.style)
+}
+
+/**
+Untangle a path that crosses itself into simple regions, where holes are added as extra
+rings, like the results of [`boolean`]. Areas where the path winds around more than once
+are filled, i.e, the nonzero fill rule.
+*/
+pub fn resolve(path: &Path) -> Vec<Path> {
+    let rings: Vec<Vec<Point>> = path.flatten(0.1).rings();
+
+    assemble(outline(&rings, |winding| winding != 0), path.style)
+}
+
+/// Everything that is inside either {a} or {b}, see [`boolean`].
+pub fn union(a: &dyn Shape, b: &dyn Shape) -> Vec<Path> {
+    boolean(a, b, BooleanOperation::Union)
+}
+
+/// Everything that is inside both {a} and {b}, see [`boolean`].
+pub fn intersection(a: &dyn Shape, b: &dyn Shape) -> Vec<Path> {
+    boolean(a, b, BooleanOperation::Intersection)
+}
+
+/// Everything that is inside {a} but not inside {b}, see [`boolean`].
+pub fn difference(a: &dyn Shape, b: &dyn Shape) -> Vec<Path> {
+    boolean(a, b, BooleanOperation::Difference)
+}
+
+/// Everything that is inside exactly one of {a} and {b}, see [`boolean`].
+pub fn xor(a: &dyn Shape, b: &dyn Shape) -> Vec<Path> {
+    boolean(a, b, BooleanOperation::Xor)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{
+        circle::Circle, path::Path, point::Point, rectangle::Rectangle, shape::Shape,
+    };
+
+    use super::{difference, intersection, resolve, union, xor};
+    use crate::geometry::polygon::signed_area;
+
+    fn square(x: f64, y: f64, size: f64) -> Path {
+        Rectangle::new(Point(x, y), size, size).to_path(Default::default())
+    }
+
+    fn area(paths: &[Path]) -> f64 {
+        paths
+            .iter()
+            .flat_map(|path| path.rings())
+            .map(|ring| signed_area(&ring) / 2.0)
+            .sum::<f64>()
+            .abs()
+    }
+
+    #[test]
+    fn union_of_overlapping_squares() {
+        let result = union(&square(0., 0., 10.), &square(5., 5., 10.));
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].rings()[0].len(), 8);
+        assert!((area(&result) - 175.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn union_of_separate_squares() {
+        let result = union(&square(0., 0., 10.), &square(20., 0., 10.));
+
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn intersection_of_squares() {
+        let result = intersection(&square(0., 0., 10.), &square(5., 5., 10.));
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].bounding_box(),
+            Some(Rectangle::new(Point(5., 5.), 5., 5.))
+        );
+    }
+
+    #[test]
+    fn difference_makes_hole() {
+        let result = difference(&square(0., 0., 10.), &square(3., 3., 4.));
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].rings().len(), 2);
+        assert!((area(&result) - 84.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn difference_with_shared_edge() {
+        let result = difference(&square(0., 0., 10.), &square(0., 0., 5.));
+
+        assert_eq!(result.len(), 1);
+        assert!((area(&result) - 75.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn union_of_circles() {
+        let a = Circle::new(Point(0., 0.), 100.).to_path(Default::default());
+        let b = Circle::new(Point(100., 0.), 100.).to_path(Default::default());
+
+        let result = union(&a, &b);
+
+        assert_eq!(result.len(), 1);
+        // Slightly smaller than the true area since the circles are polygons
+        assert!((area(&result) - 50548.).abs() < 200.);
+    }
+
+    #[test]
+    fn shapes_without_converting() {
+        let square = Rectangle::new(Point(0., 0.), 100., 100.);
+        let hole = Circle::new(Point(50., 50.), 20.);
+
+        let result = difference(&square, &hole);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].rings().len(), 2);
+        // The circle is a polygon within 0.1 of its outline, so a little smaller
+        assert!((area(&result) - (10000. - hole.area())).abs() < 20.);
+        assert!((area(&intersection(&hole, &square)) - hole.area()).abs() < 20.);
+    }
+
+    #[test]
+    fn resolve_figure_eight() {
+        let path = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(10., 10.),
+                Point(10., 0.),
+                Point(0., 10.),
+                Point(0., 0.),
+            ],
+            Default::default(),
+        );
+
+        let result = resolve(&path);
+
+        assert_eq!(result.len(), 2);
+        assert!((area(&result) - 50.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn xor_of_squares() {
+        let result = xor(&square(0., 0., 10.), &square(5., 5., 10.));
+
+        assert_eq!(result.len(), 2);
+        assert!((area(&result) - 150.).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use crate::shapes::{path::Path, point::Point, rectangle::Rectangle};
+
+/**
+A scalar function, like a noise field or a signed distance, sampled on a grid over a
+rectangle. Isolines are found with marching squares, and joined up into continuous lines
+that are closed where they loop around and open where they leave the bounds.
+
+Example
+```
+use generative_art::{
+    geometry::contour::ScalarField,
+    shapes::{point::Point, rectangle::Rectangle},
+};
+use noise::{NoiseFn, Perlin};
+
+let noise = Perlin::new();
+let bounds = Rectangle::new(Point(0.0, 0.0), 200.0, 200.0);
+
+let mut field = ScalarField::new(&bounds, 2.0, |point| {
+    noise.get([point.0 / 80.0, point.1 / 80.0])
+});
+field.smoothing = 2;
+
+// A topographic map, with one list of lines per level
+let levels: Vec<f64> = (-4..=4).map(|i| i as f64 / 10.0).collect();
+let contours = field.contours(&levels);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct ScalarField {
+    origin: Point,
+    cell_size: f64,
+    columns: usize,
+    rows: usize,
+
+    /// Sampled values, row by row, with one more value than cells in each direction
+    values: Vec<f64>,
+
+    /// Cut the corners of the isolines this many times to smooth them, see [`Path::chaikin`]
+    pub smoothing: usize,
+}
+
+/// An edge between two samples of the grid, horizontal or vertical from the sample at (x, y)
+type GridEdge = (bool, usize, usize);
+
+impl ScalarField {
+    /// Sample {field} over {bounds} every {cell_size}, the last row and column of cells
+    /// may reach a bit past the bounds.
+    pub fn new(bounds: &Rectangle, cell_size: f64, field: impl Fn(&Point) -> f64) -> ScalarField {
+        let cell_size = if cell_size > 0.0 {
+            cell_size
+        } else {
+            bounds.width.max(bounds.height).max(1.0)
+        };
+        let columns = ((bounds.width / cell_size).ceil() as usize).max(1);
+        let rows = ((bounds.height / cell_size).ceil() as usize).max(1);
+        let origin = bounds.position;
+
+        let values = (0..=rows)
+            .flat_map(|y| (0..=columns).map(move |x| (x, y)))
+            .map(|(x, y)| field(&origin.offset(x as f64 * cell_size, y as f64 * cell_size)))
+            .collect();
+
+        ScalarField {
+            origin,
+            cell_size,
+            columns,
+            rows,
+            values,
+            smoothing: 0,
+        }
+    }
+
+    /// The isolines for each of the {levels}, in the same order
+    pub fn contours(&self, levels: &[f64]) -> Vec<Vec<Path>> {
+        levels.iter().map(|level| self.isolines(*level)).collect()
+    }
+
+    /// The lines where the field crosses {level}, i.e. the outlines of the areas where
+    /// the field is at or above {level}.
+    pub fn isolines(&self, level: f64) -> Vec<Path> {
+        let mut segments: Vec<(GridEdge, GridEdge)> = vec![];
+
+        for y in 0..self.rows {
+            for x in 0..self.columns {
+                segments.extend(self.cell_segments(x, y, level));
+            }
+        }
+
+        join(&segments)
+            .into_iter()
+            .map(|edges| {
+                let points = edges
+                    .iter()
+                    .map(|edge| self.crossing(edge, level))
+                    .collect();
+                let path = Path::new(points, Default::default());
+
+                if self.smoothing > 0 {
+                    path.chaikin(self.smoothing)
+                } else {
+                    path
+                }
+            })
+            .collect()
+    }
+
+    fn value(&self, x: usize, y: usize) -> f64 {
+        self.values[y * (self.columns + 1) + x]
+    }
+
+    fn above(&self, x: usize, y: usize, level: f64) -> bool {
+        self.value(x, y) >= level
+    }
+
+    /// The lines through the cell at ({x}, {y}) as pairs of the edges they cross
+    fn cell_segments(&self, x: usize, y: usize, level: f64) -> Vec<(GridEdge, GridEdge)> {
+        let bottom = (true, x, y);
+        let top = (true, x, y + 1);
+        let left = (false, x, y);
+        let right = (false, x + 1, y);
+
+        let corners = [
+            self.above(x, y, level),
+            self.above(x + 1, y, level),
+            self.above(x + 1, y + 1, level),
+            self.above(x, y + 1, level),
+        ];
+        let case = corners
+            .iter()
+            .enumerate()
+            .fold(0, |case, (i, above)| case | (usize::from(*above) << i));
+
+        // The middle of the cell decides how to pass between two diagonal corners above the level
+        let middle = (self.value(x, y)
+            + self.value(x + 1, y)
+            + self.value(x + 1, y + 1)
+            + self.value(x, y + 1))
+            / 4.0
+            >= level;
+
+        match case {
+            0 | 15 => vec![],
+            1 | 14 => vec![(left, bottom)],
+            2 | 13 => vec![(bottom, right)],
+            3 | 12 => vec![(left, right)],
+            4 | 11 => vec![(right, top)],
+            6 | 9 => vec![(bottom, top)],
+            7 | 8 => vec![(left, top)],
+            5 if middle => vec![(left, top), (bottom, right)],
+            5 => vec![(left, bottom), (right, top)],
+            10 if middle => vec![(left, bottom), (right, top)],
+            _ => vec![(left, top), (bottom, right)],
+        }
+    }
+
+    /// Where along {edge} the field crosses {level}
+    fn crossing(&self, edge: &GridEdge, level: f64) -> Point {
+        let &(horizontal, x, y) = edge;
+        let (x2, y2) = if horizontal { (x + 1, y) } else { (x, y + 1) };
+        let (a, b) = (self.value(x, y), self.value(x2, y2));
+
+        let t = if (b - a).abs() > f64::EPSILON {
+            ((level - a) / (b - a)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+
+        let start = self
+            .origin
+            .offset(x as f64 * self.cell_size, y as f64 * self.cell_size);
+        if horizontal {
+            start.offset(t * self.cell_size, 0.0)
+        } else {
+            start.offset(0.0, t * self.cell_size)
+        }
+    }
+}
+
+/// Chain segments that share an edge into lines, closed lines end with their first edge
+fn join(segments: &[(GridEdge, GridEdge)]) -> Vec<Vec<GridEdge>> {
+    let mut touching: HashMap<GridEdge, Vec<usize>> = HashMap::new();
+    for (i, (a, b)) in segments.iter().enumerate() {
+        touching.entry(*a).or_default().push(i);
+        touching.entry(*b).or_default().push(i);
+    }
+
+    let mut used = vec![false; segments.len()];
+    let follow = |start: usize, from: GridEdge, used: &mut Vec<bool>| {
+        let mut line = vec![from];
+        let (mut current, mut at) = (start, from);
+
+        loop {
+            used[current] = true;
+            let (a, b) = segments[current];
+            at = if a == at { b } else { a };
+            line.push(at);
+
+            match touching[&at].iter().find(|i| !used[**i]) {
+                Some(next) => current = *next,
+                None => break,
+            }
+        }
+
+        line
+    };
+
+    let mut lines = vec![];
+
+    // Open lines start and end at the border, where an edge is only crossed once
+    for (i, (a, b)) in segments.iter().enumerate() {
+        if used[i] {
+            continue;
+        }
+        if touching[a].len() == 1 {
+            lines.push(follow(i, *a, &mut used));
+        } else if touching[b].len() == 1 {
+            lines.push(follow(i, *b, &mut used));
+        }
+    }
+
+    // Everything that is left goes around in loops
+    for (i, (a, _)) in segments.iter().enumerate() {
+        if !used[i] {
+            lines.push(follow(i, *a, &mut used));
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{point::Point, rectangle::Rectangle, shape::Shape};
+
+    use super::ScalarField;
+
+    #[test]
+    fn circle_gives_closed_loop() {
+        let bounds = Rectangle::new(Point(0., 0.), 100., 100.);
+        let center = Point(50., 50.);
+        let field = ScalarField::new(&bounds, 2., |p| -p.distance_to(&center));
+
+        let lines = field.isolines(-30.);
+        assert_eq!(lines.len(), 1);
+
+        let line = &lines[0];
+        assert_eq!(line.points.first(), line.points.last());
+        assert!(line
+            .points
+            .iter()
+            .all(|p| (p.distance_to(&center) - 30.).abs() < 0.5));
+        assert!((line.area() - std::f64::consts::PI * 900.).abs() < 20.);
+    }
+
+    #[test]
+    fn open_lines_end_at_the_border() {
+        let bounds = Rectangle::new(Point(0., 0.), 100., 50.);
+        let field = ScalarField::new(&bounds, 5., |p| p.0 + p.1 * 0.01);
+
+        let contours = field.contours(&[27., 52., 77., 500.]);
+        assert_eq!(
+            contours.iter().map(|lines| lines.len()).collect::<Vec<_>>(),
+            vec![1, 1, 1, 0]
+        );
+
+        let line = &contours[1][0];
+        assert_eq!(line.points.len(), 11);
+        for p in [line.points.first().unwrap(), line.points.last().unwrap()] {
+            assert!(p.1 == 0. || p.1 == 50.);
+        }
+    }
+
+    #[test]
+    fn separate_islands_and_smoothing() {
+        let bounds = Rectangle::new(Point(0., 0.), 100., 100.);
+        let (a, b) = (Point(35., 50.), Point(65., 50.));
+        let mut field =
+            ScalarField::new(&bounds, 2., |p| -p.distance_to(&a).min(p.distance_to(&b)));
+
+        assert_eq!(field.isolines(-10.).len(), 2);
+        assert_eq!(field.isolines(-20.).len(), 1);
+
+        let rough = field.isolines(-10.)[0].points.len();
+        field.smoothing = 2;
+        let smooth = &field.isolines(-10.)[0];
+        assert!(smooth.points.len() > rough);
+        assert_eq!(smooth.points.first(), smooth.points.last());
+    }
+}
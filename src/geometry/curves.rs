@@ -0,0 +1,384 @@
+use std::f64::consts::PI;
+
+use crate::shapes::{path::Path, point::Point, rectangle::Rectangle};
+
+/// The kinds of space-filling curves, see [`space_filling_curve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceFillingCurve {
+    /// Splits the bounds into 2 by 2 cells each order, starting in one corner and
+    /// ending in the other corner along the same side.
+    Hilbert,
+
+    /// Splits the bounds into 3 by 3 cells each order, starting in one corner and
+    /// ending in the opposite corner.
+    Peano,
+
+    /// Four Hilbert curves joined up into a closed loop.
+    Moore,
+
+    /// The flowsnake, which replaces each line with seven shorter ones each order and fills
+    /// a hexagon-like island. It keeps its proportions and is centered in the bounds.
+    Gosper,
+}
+
+/**
+A single continuous line that visits every cell of a grid over {bounds}, which is ideal for
+plotting since the pen never has to be lifted. Each {order} makes the cells smaller, so the
+number of points grows quickly: 4, 9, 4 and 7 times more points per order. An order of at
+least 1 is used.
+
+Example
+```
+use generative_art::{
+    geometry::curves::{space_filling_curve, SpaceFillingCurve},
+    shapes::{point::Point, rectangle::Rectangle},
+};
+
+let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+let path = space_filling_curve(SpaceFillingCurve::Hilbert, 3, &bounds);
+
+assert_eq!(path.points.len(), 64);
+```
+*/
+pub fn space_filling_curve(curve: SpaceFillingCurve, order: usize, bounds: &Rectangle) -> Path {
+    space_filling_curve_with_density(curve, order, bounds, |_| 1.0)
+}
+
+/**
+Like [`space_filling_curve`], but {density} decides how small the cells are around each
+point, from 0 for the largest cells to 1 for cells of the full {order}. The line stays
+continuous, with short steps where the density is high and long steps where it is low.
+
+Example
+```
+use generative_art::{
+    geometry::curves::{space_filling_curve_with_density, SpaceFillingCurve},
+    shapes::{point::Point, rectangle::Rectangle},
+};
+
+// Finer towards the center
+let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+let center = Point(50.0, 50.0);
+let path = space_filling_curve_with_density(SpaceFillingCurve::Peano, 4, &bounds, |point| {
+    1.0 - point.distance_to(&center) / 70.0
+});
+```
+*/
+pub fn space_filling_curve_with_density(
+    curve: SpaceFillingCurve,
+    order: usize,
+    bounds: &Rectangle,
+    density: impl Fn(&Point) -> f64,
+) -> Path {
+    let order = order.max(1);
+    let refine = |center: &Point, level: usize| {
+        level < order && level < ((density(center) * order as f64).ceil() as usize).max(1)
+    };
+
+    let origin = bounds.position;
+    let (x, y) = (Point(bounds.width, 0.0), Point(0.0, bounds.height));
+    let mut points = vec![];
+
+    match curve {
+        SpaceFillingCurve::Hilbert => hilbert(&origin, &x, &y, 0, &refine, &mut points),
+        SpaceFillingCurve::Peano => peano(&origin, &x, &y, 0, &refine, &mut points),
+        SpaceFillingCurve::Moore => {
+            let (x, y) = (half(&x), half(&y));
+            let (minus_x, minus_y) = (Point(-x.0, -x.1), Point(-y.0, -y.1));
+            let middle = at(&origin, &x, &y, 1.0, 1.0);
+
+            // Up the left half and back down the right half
+            let quadrants = [
+                (at(&origin, &x, &y, 1.0, 0.0), y, minus_x),
+                (middle, y, minus_x),
+                (at(&origin, &x, &y, 1.0, 2.0), minus_y, x),
+                (middle, minus_y, x),
+            ];
+
+            for (corner, x, y) in quadrants {
+                hilbert(&corner, &x, &y, 1, &refine, &mut points);
+            }
+
+            if let Some(first) = points.first().copied() {
+                points.push(first);
+            }
+        }
+        SpaceFillingCurve::Gosper => {
+            // Draw the curve once at its full size to find out how to fit it in the bounds
+            let mut full = vec![Point(0.0, 0.0)];
+            gosper(
+                &Point(0.0, 0.0),
+                &Point(1.0, 0.0),
+                Flowsnake::A,
+                0,
+                &|_, level| level < order,
+                &mut full,
+            );
+
+            let fit = fit(&full, bounds);
+            let mut unit = vec![Point(0.0, 0.0)];
+            gosper(
+                &Point(0.0, 0.0),
+                &Point(1.0, 0.0),
+                Flowsnake::A,
+                0,
+                &|center, level| refine(&fit(center), level),
+                &mut unit,
+            );
+            points = unit.iter().map(&fit).collect();
+        }
+    }
+
+    Path::new(points, Default::default())
+}
+
+/// The point at {a} times {x} plus {b} times {y} away from {origin}
+fn at(origin: &Point, x: &Point, y: &Point, a: f64, b: f64) -> Point {
+    origin.offset(x.0 * a + y.0 * b, x.1 * a + y.1 * b)
+}
+
+fn half(vector: &Point) -> Point {
+    Point(vector.0 / 2.0, vector.1 / 2.0)
+}
+
+/// Visit the cell spanned by {x} and {y} from {origin}, starting next to {origin} and ending
+/// next to the corner at {x}.
+fn hilbert(
+    origin: &Point,
+    x: &Point,
+    y: &Point,
+    level: usize,
+    refine: &dyn Fn(&Point, usize) -> bool,
+    points: &mut Vec<Point>,
+) {
+    let center = at(origin, x, y, 0.5, 0.5);
+    if !refine(&center, level) {
+        points.push(center);
+        return;
+    }
+
+    let (x, y) = (half(x), half(y));
+    let (minus_x, minus_y) = (Point(-x.0, -x.1), Point(-y.0, -y.1));
+
+    hilbert(origin, &y, &x, level + 1, refine, points);
+    hilbert(
+        &at(origin, &x, &y, 0.0, 1.0),
+        &x,
+        &y,
+        level + 1,
+        refine,
+        points,
+    );
+    hilbert(
+        &at(origin, &x, &y, 1.0, 1.0),
+        &x,
+        &y,
+        level + 1,
+        refine,
+        points,
+    );
+    hilbert(
+        &at(origin, &x, &y, 2.0, 1.0),
+        &minus_y,
+        &minus_x,
+        level + 1,
+        refine,
+        points,
+    );
+}
+
+/// Visit the cell spanned by {x} and {y} from {origin}, starting next to {origin} and ending
+/// next to the opposite corner, going back and forth along {y}.
+fn peano(
+    origin: &Point,
+    x: &Point,
+    y: &Point,
+    level: usize,
+    refine: &dyn Fn(&Point, usize) -> bool,
+    points: &mut Vec<Point>,
+) {
+    let center = at(origin, x, y, 0.5, 0.5);
+    if !refine(&center, level) {
+        points.push(center);
+        return;
+    }
+
+    let (x, y) = (Point(x.0 / 3.0, x.1 / 3.0), Point(y.0 / 3.0, y.1 / 3.0));
+
+    for column in 0..3 {
+        for step in 0..3 {
+            let row = if column % 2 == 0 { step } else { 2 - step };
+
+            // Every other cell is mirrored, so each one starts where the last one ended
+            let (flip_x, flip_y) = (row % 2 == 1, column % 2 == 1);
+            let corner = at(
+                origin,
+                &x,
+                &y,
+                (column + usize::from(flip_x)) as f64,
+                (row + usize::from(flip_y)) as f64,
+            );
+            let sub_x = if flip_x { Point(-x.0, -x.1) } else { x };
+            let sub_y = if flip_y { Point(-y.0, -y.1) } else { y };
+
+            peano(&corner, &sub_x, &sub_y, level + 1, refine, points);
+        }
+    }
+}
+
+/// The two kinds of lines in the Gosper curve, which are replaced by different patterns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flowsnake {
+    A,
+    B,
+}
+
+impl Flowsnake {
+    /// The seven shorter lines that replace this one, as the direction of each line in
+    /// sixths of a full turn, from the L-system rules A → A-B--B+A++AA+B- and
+    /// B → +A-BB--B-A++A+B.
+    fn replacement(&self) -> [(i32, Flowsnake); 7] {
+        use Flowsnake::{A, B};
+
+        match self {
+            A => [(0, A), (-1, B), (-3, B), (-2, A), (0, A), (0, A), (1, B)],
+            B => [(1, A), (0, B), (0, B), (-2, B), (-3, A), (-1, A), (0, B)],
+        }
+    }
+}
+
+/// Add the points of the Gosper curve going from {from} to {to}, not including {from}
+fn gosper(
+    from: &Point,
+    to: &Point,
+    kind: Flowsnake,
+    level: usize,
+    refine: &dyn Fn(&Point, usize) -> bool,
+    points: &mut Vec<Point>,
+) {
+    if !refine(&from.between(to, 0.5), level) {
+        points.push(*to);
+        return;
+    }
+
+    // The replacement lines end up turned away from the line they replace
+    let length = from.distance_to(to) / 7_f64.sqrt();
+    let angle = (to.1 - from.1).atan2(to.0 - from.0) + (3_f64.sqrt() / 5.0).atan();
+
+    let replacement = kind.replacement();
+    let mut start = *from;
+    for (i, (turn, kind)) in replacement.iter().enumerate() {
+        let direction = angle + *turn as f64 * PI / 3.0;
+        let end = if i == replacement.len() - 1 {
+            *to
+        } else {
+            start.offset(direction.cos() * length, direction.sin() * length)
+        };
+
+        gosper(&start, &end, *kind, level + 1, refine, points);
+        start = end;
+    }
+}
+
+/// Scale and move {points} to fit in the middle of {bounds}, keeping their proportions
+fn fit(points: &[Point], bounds: &Rectangle) -> impl Fn(&Point) -> Point {
+    let (mut min, mut max) = (
+        Point(f64::INFINITY, f64::INFINITY),
+        Point(f64::MIN, f64::MIN),
+    );
+    for point in points {
+        min = Point(min.0.min(point.0), min.1.min(point.1));
+        max = Point(max.0.max(point.0), max.1.max(point.1));
+    }
+
+    let (width, height) = ((max.0 - min.0).max(1e-9), (max.1 - min.1).max(1e-9));
+    let scale = (bounds.width / width).min(bounds.height / height);
+    let offset = Point(
+        bounds.position.0 + (bounds.width - width * scale) / 2.0,
+        bounds.position.1 + (bounds.height - height * scale) / 2.0,
+    );
+
+    move |point| {
+        Point(
+            offset.0 + (point.0 - min.0) * scale,
+            offset.1 + (point.1 - min.1) * scale,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        geometry::intersection::self_intersections,
+        shapes::{path::Path, point::Point, rectangle::Rectangle, shape::Shape},
+    };
+
+    use super::{space_filling_curve, space_filling_curve_with_density, SpaceFillingCurve};
+
+    fn bounds() -> Rectangle {
+        Rectangle::new(Point(10., 20.), 90., 90.)
+    }
+
+    /// Every step of the curve goes to a neighboring cell
+    fn assert_steps(path: &Path, step: f64) {
+        for pair in path.points.windows(2) {
+            assert!((pair[0].distance_to(&pair[1]) - step).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn grid_curves_visit_every_cell() {
+        let hilbert = space_filling_curve(SpaceFillingCurve::Hilbert, 3, &bounds());
+        assert_eq!(hilbert.points.len(), 64);
+        assert_steps(&hilbert, 90. / 8.);
+
+        let peano = space_filling_curve(SpaceFillingCurve::Peano, 2, &bounds());
+        assert_eq!(peano.points.len(), 81);
+        assert_steps(&peano, 10.);
+
+        let moore = space_filling_curve(SpaceFillingCurve::Moore, 3, &bounds());
+        assert_eq!(moore.points.len(), 65);
+        assert_eq!(moore.points.first(), moore.points.last());
+        assert_steps(&moore, 90. / 8.);
+
+        for path in [hilbert, peano, moore] {
+            let mut points = path.points.clone();
+            points.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+            points.dedup();
+            assert!(points.len() >= path.points.len() - 1);
+            assert!(points.iter().all(|p| bounds().contains(p)));
+        }
+    }
+
+    #[test]
+    fn gosper_fits_bounds() {
+        let gosper = space_filling_curve(SpaceFillingCurve::Gosper, 3, &bounds());
+        assert_eq!(gosper.points.len(), 7usize.pow(3) + 1);
+
+        let step = gosper.points[0].distance_to(&gosper.points[1]);
+        assert_steps(&gosper, step);
+        assert!(self_intersections(&gosper).is_empty());
+
+        let fitted = gosper.bounding_box().unwrap();
+        assert!((fitted.width - 90.).abs() < 1e-9 || (fitted.height - 90.).abs() < 1e-9);
+        assert!(gosper
+            .points
+            .iter()
+            .all(|p| bounds().scale(1.0001).contains(p)));
+    }
+
+    #[test]
+    fn density_changes_cell_size() {
+        let dense = |p: &Point| if p.0 < 55. { 1. } else { 0. };
+
+        let hilbert =
+            space_filling_curve_with_density(SpaceFillingCurve::Hilbert, 4, &bounds(), dense);
+        let left = hilbert.points.iter().filter(|p| p.0 < 55.).count();
+        assert_eq!(left, 128);
+        assert!(hilbert.points.len() < 140);
+
+        let gosper =
+            space_filling_curve_with_density(SpaceFillingCurve::Gosper, 3, &bounds(), dense);
+        assert!(gosper.points.len() < 7usize.pow(3));
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+
+use crate::shapes::{
+    path::{Path, PathStyle},
+    point::Point,
+    rectangle::Rectangle,
+};
+
+use super::polygon::{cross, difference_of, dot, EPSILON};
+
+/**
+A Delaunay triangulation of a set of points, where no point lies inside the circle through
+the corners of any triangle. This gives the triangles that are as close to equilateral as
+possible, and is the dual of the Voronoi diagram, see [`Triangulation::voronoi`].
+
+Example
+```
+use generative_art::{
+    geometry::delaunay::Triangulation,
+    shapes::{point::Point, rectangle::Rectangle},
+};
+
+let points = vec![
+    Point(10.0, 10.0),
+    Point(90.0, 20.0),
+    Point(50.0, 50.0),
+    Point(20.0, 80.0),
+    Point(80.0, 90.0),
+];
+
+let triangulation = Triangulation::new(&points);
+let mesh = triangulation.to_paths(Default::default());
+
+let cells = triangulation.voronoi(&Rectangle::new(Point(0.0, 0.0), 100.0, 100.0));
+assert_eq!(cells.len(), points.len());
+assert_eq!(cells[2].neighbors.len(), 4);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct Triangulation {
+    /// The points that were triangulated
+    pub points: Vec<Point>,
+
+    /// Each triangle as indices into {points}
+    pub triangles: Vec<[usize; 3]>,
+
+    /// For each point, the indices of the points it shares an edge with
+    neighbors: Vec<Vec<usize>>,
+
+    /// For each point, the index of the first point at the same position, which is the
+    /// point itself unless it is a duplicate
+    first: Vec<usize>,
+}
+
+/// A single cell of a Voronoi diagram: the area that is closer to its site than to any other site.
+#[derive(Debug, Clone)]
+pub struct VoronoiCell {
+    /// The point this cell belongs to
+    pub site: Point,
+
+    /// The outline of the cell as a closed path, which is empty if the site is outside the bounds
+    pub path: Path,
+
+    /// Indices of the cells that share an edge with this one
+    pub neighbors: Vec<usize>,
+}
+
+/// A triangle while the triangulation is built, with its circumcircle to speed up lookups
+struct Triangle {
+    corners: [usize; 3],
+    center: Point,
+    radius_squared: f64,
+}
+
+impl Triangulation {
+    /// Triangulate the given points, using the Bowyer-Watson algorithm. Points that are
+    /// at the same position as an earlier point are not part of any triangle.
+    pub fn new(points: &[Point]) -> Triangulation {
+        let count = points.len();
+        let mut vertices = points.to_vec();
+
+        // A triangle large enough that all points are well inside it
+        let (mut min, mut max) = (Point(0.0, 0.0), Point(0.0, 0.0));
+        if let Some(first) = points.first() {
+            min = *first;
+            max = *first;
+        }
+        for point in points {
+            min = Point(min.0.min(point.0), min.1.min(point.1));
+            max = Point(max.0.max(point.0), max.1.max(point.1));
+        }
+        let size = (max.0 - min.0).max(max.1 - min.1).max(1.0) * 100.0;
+        let middle = min.between(&max, 0.5);
+        vertices.push(middle.offset(-size, -size));
+        vertices.push(middle.offset(size, -size));
+        vertices.push(middle.offset(0.0, size));
+
+        let mut triangles = vec![Triangle::new([count, count + 1, count + 2], &vertices)];
+        let mut first: Vec<usize> = (0..count).collect();
+
+        for (i, point) in points.iter().enumerate() {
+            if let Some(j) = points[..i]
+                .iter()
+                .position(|other| other.distance_to(point) < EPSILON)
+            {
+                first[i] = j;
+                continue;
+            }
+
+            let (bad, good): (Vec<Triangle>, Vec<Triangle>) = triangles
+                .into_iter()
+                .partition(|triangle| triangle.circumcircle_contains(point));
+            triangles = good;
+
+            // The edges around the hole left by the removed triangles, edges shared by two
+            // removed triangles are inside the hole.
+            let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+            for triangle in &bad {
+                for k in 0..3 {
+                    let (a, b) = (triangle.corners[k], triangle.corners[(k + 1) % 3]);
+                    *edges.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+                }
+            }
+
+            for ((a, b), uses) in edges {
+                if uses == 1 {
+                    triangles.push(Triangle::new([a, b, i], &vertices));
+                }
+            }
+        }
+
+        let mut neighbors: Vec<Vec<usize>> = vec![vec![]; count];
+        for triangle in &triangles {
+            for k in 0..3 {
+                let (a, b) = (triangle.corners[k], triangle.corners[(k + 1) % 3]);
+                if a < count && b < count && !neighbors[a].contains(&b) {
+                    neighbors[a].push(b);
+                    neighbors[b].push(a);
+                }
+            }
+        }
+        neighbors.iter_mut().for_each(|list| list.sort_unstable());
+
+        Triangulation {
+            points: points.to_vec(),
+            triangles: triangles
+                .into_iter()
+                .map(|triangle| triangle.corners)
+                .filter(|corners| corners.iter().all(|corner| *corner < count))
+                .collect(),
+            neighbors,
+            first,
+        }
+    }
+
+    /// Indices of the points that share an edge with the point at {index}
+    pub fn neighbors(&self, index: usize) -> &[usize] {
+        self.neighbors
+            .get(index)
+            .map_or(&[], |list| list.as_slice())
+    }
+
+    /// Every edge of the triangulation once, as pairs of indices into {points}
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        self.neighbors
+            .iter()
+            .enumerate()
+            .flat_map(|(a, list)| list.iter().filter(move |b| a < **b).map(move |b| (a, *b)))
+            .collect()
+    }
+
+    /// Each triangle as a closed [`Path`] with the given style
+    pub fn to_paths(&self, style: PathStyle) -> Vec<Path> {
+        self.triangles
+            .iter()
+            .map(|[a, b, c]| {
+                Path::new(
+                    vec![
+                        self.points[*a],
+                        self.points[*b],
+                        self.points[*c],
+                        self.points[*a],
+                    ],
+                    style,
+                )
+            })
+            .collect()
+    }
+
+    /**
+    The Voronoi diagram of the points, cut off at {bounds}. There is one cell per point, in
+    the same order as {points}. Cells are found by cutting {bounds} in half between the site
+    and each of its neighbors in the triangulation, keeping the half closest to the site.
+
+    Points at the same position as an earlier point get a copy of the cell of the first point
+    at that position, so the cells of duplicates overlap exactly.
+    */
+    pub fn voronoi(&self, bounds: &Rectangle) -> Vec<VoronoiCell> {
+        let (x1, y1) = (bounds.position.0, bounds.position.1);
+        let (x2, y2) = (x1 + bounds.width, y1 + bounds.height);
+
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(i, site)| {
+                let owner = self.first[i];
+
+                // Each corner is paired with the neighbor whose border starts at that corner,
+                // None for the edges of the bounds.
+                let mut cell: Vec<(Point, Option<usize>)> = vec![
+                    (Point(x1, y1), None),
+                    (Point(x2, y1), None),
+                    (Point(x2, y2), None),
+                    (Point(x1, y2), None),
+                ];
+
+                for j in self.neighbors(owner) {
+                    cell = cut(&cell, site, &self.points[*j], *j);
+                }
+
+                let mut neighbors: Vec<usize> = cell.iter().filter_map(|(_, j)| *j).collect();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+
+                let mut points: Vec<Point> = cell.into_iter().map(|(point, _)| point).collect();
+                if let Some(first) = points.first().copied() {
+                    points.push(first);
+                }
+
+                VoronoiCell {
+                    site: *site,
+                    path: Path::new(points, Default::default()),
+                    neighbors,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Triangle {
+    fn new(corners: [usize; 3], vertices: &[Point]) -> Triangle {
+        let [a, b, c] = corners.map(|i| vertices[i]);
+        let ab = difference_of(&b, &a);
+        let ac = difference_of(&c, &a);
+        let d = 2.0 * cross(&ab, &ac);
+
+        // Points on a line have their circle infinitely far away
+        if d.abs() < EPSILON {
+            return Triangle {
+                corners,
+                center: a,
+                radius_squared: f64::INFINITY,
+            };
+        }
+
+        let (ab2, ac2) = (dot(&ab, &ab), dot(&ac, &ac));
+        let x = (ac.1 * ab2 - ab.1 * ac2) / d;
+        let y = (ab.0 * ac2 - ac.0 * ab2) / d;
+
+        Triangle {
+            corners,
+            center: a.offset(x, y),
+            radius_squared: x * x + y * y,
+        }
+    }
+
+    fn circumcircle_contains(&self, point: &Point) -> bool {
+        let offset = difference_of(point, &self.center);
+        dot(&offset, &offset) < self.radius_squared
+    }
+}
+
+/// Keep the part of {cell} that is closer to {site} than to {other}, the new edge along the
+/// border between them is marked with {label}.
+fn cut(
+    cell: &[(Point, Option<usize>)],
+    site: &Point,
+    other: &Point,
+    label: usize,
+) -> Vec<(Point, Option<usize>)> {
+    let direction = difference_of(other, site);
+    if dot(&direction, &direction) < EPSILON {
+        return cell.to_vec();
+    }
+
+    let middle = site.between(other, 0.5);
+    let side = |point: &Point| dot(&difference_of(point, &middle), &direction);
+
+    let mut result = vec![];
+    for k in 0..cell.len() {
+        let (a, edge) = cell[k];
+        let (b, _) = cell[(k + 1) % cell.len()];
+        let (side_a, side_b) = (side(&a), side(&b));
+
+        let crossing = || a.between(&b, side_a / (side_a - side_b));
+
+        if side_a <= 0.0 {
+            result.push((a, edge));
+            if side_b > 0.0 {
+                result.push((crossing(), Some(label)));
+            }
+        } else if side_b <= 0.0 {
+            result.push((crossing(), edge));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{point::Point, rectangle::Rectangle, shape::Shape};
+
+    use super::{Triangle, Triangulation};
+
+    fn grid() -> Vec<Point> {
+        (0..5)
+            .flat_map(|x| (0..4).map(move |y| Point(x as f64 * 10. + y as f64, y as f64 * 10.)))
+            .collect()
+    }
+
+    #[test]
+    fn no_point_inside_any_circumcircle() {
+        let triangulation = Triangulation::new(&grid());
+
+        // Euler: a triangulation of n points with h points on the hull has 2n - h - 2 triangles
+        assert_eq!(triangulation.triangles.len(), 2 * 20 - 14 - 2);
+
+        for [a, b, c] in &triangulation.triangles {
+            let corners = [a, b, c].map(|i| triangulation.points[*i]);
+            let circle = Triangle::new([0, 1, 2], &corners);
+
+            assert!(triangulation
+                .points
+                .iter()
+                .all(|p| circle.center.distance_to(p).powi(2) > circle.radius_squared - 1e-6));
+        }
+    }
+
+    #[test]
+    fn voronoi_cells_tile_the_bounds() {
+        let bounds = Rectangle::new(Point(-5., -5.), 60., 40.);
+        let triangulation = Triangulation::new(&grid());
+        let cells = triangulation.voronoi(&bounds);
+
+        let area: f64 = cells.iter().map(|cell| cell.path.area()).sum();
+        assert!((area - bounds.area()).abs() < 1e-6);
+
+        for (i, cell) in cells.iter().enumerate() {
+            assert!(cell.path.contains(&cell.site));
+            assert!(cell
+                .neighbors
+                .iter()
+                .all(|j| cells[*j].neighbors.contains(&i)));
+        }
+    }
+
+    #[test]
+    fn two_points() {
+        let triangulation = Triangulation::new(&[Point(0., 0.), Point(10., 0.)]);
+        let cells = triangulation.voronoi(&Rectangle::new(Point(-10., -10.), 30., 20.));
+
+        assert!(triangulation.triangles.is_empty());
+        assert_eq!(triangulation.edges(), vec![(0, 1)]);
+        assert_eq!(cells[0].path.area(), 300.);
+        assert_eq!(cells[1].neighbors, vec![0]);
+    }
+    #[test]
+    fn duplicates_share_a_cell() {
+        let points = [
+            Point(10., 10.),
+            Point(50., 10.),
+            Point(30., 40.),
+            Point(10., 10.),
+        ];
+        let bounds = Rectangle::new(Point(0., 0.), 60., 50.);
+        let cells = Triangulation::new(&points).voronoi(&bounds);
+
+        assert_eq!(cells.len(), 4);
+        assert_eq!(cells[3].path.points, cells[0].path.points);
+        assert_eq!(cells[3].neighbors, vec![1, 2]);
+        assert!(cells[3].path.area() < bounds.area() / 2.);
+
+        let area: f64 = cells[..3].iter().map(|cell| cell.path.area()).sum();
+        assert!((area - bounds.area()).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::shapes::{circle::Circle, path::Path, point::Point};
+
+use super::polygon::{cross, difference_of, dot, EPSILON};
+
+/**
+The convex hull of a set of points, i.e, the smallest convex shape that contains all of them,
+as a closed [`Path`]. Points on the straight edges of the hull are left out.
+
+Works for the points of any shape too, by converting it with
+[`Shape::to_polygon`](crate::shapes::shape::Shape::to_polygon) first.
+
+Example
+```
+use generative_art::{
+    geometry::hull::convex_hull,
+    shapes::{point::Point, shape::Shape, star::Star},
+};
+
+let star = Star::new(Point(0.0, 0.0), 5, 20.0, 8.0);
+let hull = convex_hull(&star.to_polygon(0.1).points);
+
+// Only the tips of the star are on the hull
+assert_eq!(hull.points.len(), 6);
+```
+*/
+pub fn convex_hull(points: &[Point]) -> Path {
+    Path::new(closed(hull(points)), Default::default())
+}
+
+/// The smallest circle that contains all of the points, or None if there are no points.
+pub fn enclosing_circle(points: &[Point]) -> Option<Circle> {
+    // The circle only depends on the corners of the hull
+    let corners = hull(points);
+    let first = corners.first()?;
+
+    let mut circle = Circle::new(*first, 0.0);
+
+    for i in 1..corners.len() {
+        if inside(&circle, &corners[i]) {
+            continue;
+        }
+
+        circle = Circle::new(corners[i], 0.0);
+        for j in 0..i {
+            if inside(&circle, &corners[j]) {
+                continue;
+            }
+
+            circle = diameter_circle(&corners[i], &corners[j]);
+            for k in 0..j {
+                if !inside(&circle, &corners[k]) {
+                    circle = circumcircle(&corners[i], &corners[j], &corners[k]);
+                }
+            }
+        }
+    }
+
+    Some(circle)
+}
+
+/**
+The rectangle with the smallest area that contains all of the points, which can be rotated
+in any direction, as a closed [`Path`] with four corners. None if there are no points.
+
+One side of the smallest rectangle always lies along an edge of the convex hull, so
+each edge of the hull is tried in turn.
+*/
+pub fn oriented_bounding_box(points: &[Point]) -> Option<Path> {
+    let corners = hull(points);
+    let first = corners.first()?;
+
+    let mut best: Option<(f64, [Point; 4])> = None;
+
+    for i in 0..corners.len() {
+        let edge = difference_of(&corners[(i + 1) % corners.len()], &corners[i]);
+        let length = dot(&edge, &edge).sqrt();
+        let axis = if length < EPSILON {
+            Point(1.0, 0.0)
+        } else {
+            Point(edge.0 / length, edge.1 / length)
+        };
+        let normal = Point(-axis.1, axis.0);
+
+        let (mut min_u, mut max_u, mut min_v, mut max_v) = (0.0, 0.0, 0.0, 0.0);
+        for corner in &corners {
+            let offset = difference_of(corner, first);
+            let (u, v) = (dot(&offset, &axis), dot(&offset, &normal));
+            min_u = f64::min(min_u, u);
+            max_u = f64::max(max_u, u);
+            min_v = f64::min(min_v, v);
+            max_v = f64::max(max_v, v);
+        }
+
+        let area = (max_u - min_u) * (max_v - min_v);
+        if best.is_none_or(|(smallest, _)| area < smallest) {
+            let at =
+                |u: f64, v: f64| first.offset(axis.0 * u + normal.0 * v, axis.1 * u + normal.1 * v);
+            best = Some((
+                area,
+                [
+                    at(min_u, min_v),
+                    at(max_u, min_v),
+                    at(max_u, max_v),
+                    at(min_u, max_v),
+                ],
+            ));
+        }
+    }
+
+    best.map(|(_, corners)| Path::new(closed(corners.to_vec()), Default::default()))
+}
+
+/// Corners of the convex hull using Andrew's monotone chain, without repeating the first corner.
+fn hull(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    sorted.dedup_by(|a, b| a.distance_to(b) < EPSILON);
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut lower: Vec<Point> = vec![];
+    for point in &sorted {
+        while lower.len() >= 2
+            && turn(&lower[lower.len() - 2], &lower[lower.len() - 1], point) <= EPSILON
+        {
+            lower.pop();
+        }
+        lower.push(*point);
+    }
+
+    let mut upper: Vec<Point> = vec![];
+    for point in sorted.iter().rev() {
+        while upper.len() >= 2
+            && turn(&upper[upper.len() - 2], &upper[upper.len() - 1], point) <= EPSILON
+        {
+            upper.pop();
+        }
+        upper.push(*point);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+
+    lower
+}
+
+/// Positive when going from {a} through {b} to {c} turns counter-clockwise, with the y axis up.
+fn turn(a: &Point, b: &Point, c: &Point) -> f64 {
+    cross(&difference_of(b, a), &difference_of(c, a))
+}
+
+/// Repeat the first point at the end, so the points make a closed path
+fn closed(mut points: Vec<Point>) -> Vec<Point> {
+    if let Some(first) = points.first().copied() {
+        points.push(first);
+    }
+
+    points
+}
+
+/// True if {point} is inside or on the edge of {circle}, allowing for rounding errors
+fn inside(circle: &Circle, point: &Point) -> bool {
+    circle.center.distance_to(point) <= circle.radius + 1e-7
+}
+
+/// The smallest circle through {a} and {b}
+fn diameter_circle(a: &Point, b: &Point) -> Circle {
+    Circle::new(a.between(b, 0.5), a.distance_to(b) / 2.0)
+}
+
+/// The circle through all three points, or the circle around the two furthest apart
+/// if the points lie on a line.
+fn circumcircle(a: &Point, b: &Point, c: &Point) -> Circle {
+    let ab = difference_of(b, a);
+    let ac = difference_of(c, a);
+    let d = 2.0 * cross(&ab, &ac);
+
+    if d.abs() < EPSILON {
+        return [
+            diameter_circle(a, b),
+            diameter_circle(a, c),
+            diameter_circle(b, c),
+        ]
+        .into_iter()
+        .fold(Circle::new(*a, 0.0), |largest, circle| {
+            if circle.radius > largest.radius {
+                circle
+            } else {
+                largest
+            }
+        });
+    }
+
+    let (ab2, ac2) = (dot(&ab, &ab), dot(&ac, &ac));
+    let x = (ac.1 * ab2 - ab.1 * ac2) / d;
+    let y = (ab.0 * ac2 - ac.0 * ab2) / d;
+
+    Circle::new(a.offset(x, y), (x * x + y * y).sqrt())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{point::Point, shape::Shape};
+
+    use super::{convex_hull, enclosing_circle, oriented_bounding_box};
+
+    #[test]
+    fn hull_of_square_with_inner_points() {
+        let points = vec![
+            Point(0., 0.),
+            Point(5., 5.),
+            Point(10., 0.),
+            Point(5., 0.),
+            Point(10., 10.),
+            Point(2., 8.),
+            Point(0., 10.),
+        ];
+
+        let hull = convex_hull(&points);
+
+        assert_eq!(hull.points.len(), 5);
+        assert_eq!(hull.points.first(), hull.points.last());
+        assert_eq!(hull.area(), 100.);
+    }
+
+    #[test]
+    fn smallest_circle() {
+        let points = vec![Point(0., 0.), Point(10., 0.), Point(5., 1.), Point(5., -2.)];
+        let circle = enclosing_circle(&points).unwrap();
+
+        assert_eq!(circle.center, Point(5., 0.));
+        assert_eq!(circle.radius, 5.);
+
+        let triangle = vec![Point(0., 0.), Point(10., 0.), Point(5., 8.)];
+        let circle = enclosing_circle(&triangle).unwrap();
+
+        assert!(triangle
+            .iter()
+            .all(|p| (circle.center.distance_to(p) - circle.radius).abs() < 1e-9));
+        assert_eq!(enclosing_circle(&[]), None);
+    }
+
+    #[test]
+    fn rotated_box() {
+        // A 10 by 2 rectangle rotated 45 degrees
+        let corners: Vec<Point> = [Point(0., 0.), Point(10., 0.), Point(10., 2.), Point(0., 2.)]
+            .iter()
+            .map(|p| p.rotate(std::f64::consts::FRAC_PI_4, &Point(0., 0.)))
+            .collect();
+
+        let bounds = oriented_bounding_box(&corners).unwrap();
+
+        assert_eq!(bounds.points.len(), 5);
+        assert!((bounds.area() - 20.).abs() < 1e-9);
+    }
+}
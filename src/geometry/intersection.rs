@@ -0,0 +1,385 @@
+use crate::shapes::{circle::Circle, line::Line, path::Path, point::Point, rectangle::Rectangle};
+
+use super::polygon::{
+    boxes_overlap, difference_of, dot, key, segment_intersections, Edge, EPSILON,
+};
+
+/**
+The points where two line segments cross or touch, at most one point unless the segments
+lie on top of each other, in which case the ends of the overlap are returned.
+
+Example
+```
+use generative_art::{
+    geometry::intersection::segment_segment,
+    shapes::{line::Line, point::Point},
+};
+
+let a = Line::new(Point(0.0, 0.0), Point(10.0, 10.0), Default::default());
+let b = Line::new(Point(0.0, 10.0), Point(10.0, 0.0), Default::default());
+
+assert_eq!(segment_segment(&a, &b), vec![Point(5.0, 5.0)]);
+```
+*/
+pub fn segment_segment(a: &Line, b: &Line) -> Vec<Point> {
+    edge_edge(&(a.start, a.end), &(b.start, b.end))
+}
+
+/// The points where a line segment crosses or touches the outline of a circle, ordered by
+/// how far they are from the start of the line.
+pub fn segment_circle(line: &Line, circle: &Circle) -> Vec<Point> {
+    edge_circle(&(line.start, line.end), circle)
+}
+
+/// The points where a line segment crosses the outline of a rectangle, ordered by how far
+/// they are from the start of the line.
+pub fn segment_rectangle(line: &Line, rect: &Rectangle) -> Vec<Point> {
+    sorted_from(
+        &line.start,
+        edges_edges(&[(line.start, line.end)], &rectangle_edges(rect)),
+    )
+}
+
+/// The points where the outlines of two circles cross or touch.
+pub fn circle_circle(a: &Circle, b: &Circle) -> Vec<Point> {
+    let offset = difference_of(&b.center, &a.center);
+    let distance = dot(&offset, &offset).sqrt();
+
+    if distance < EPSILON
+        || distance > a.radius + b.radius + EPSILON
+        || distance < (a.radius - b.radius).abs() - EPSILON
+    {
+        return vec![];
+    }
+
+    // How far along the line between the centers the crossing points are, and how far to each side
+    let along = (distance.powi(2) + a.radius.powi(2) - b.radius.powi(2)) / (2.0 * distance);
+    let side = (a.radius.powi(2) - along.powi(2)).max(0.0).sqrt();
+    let (unit, normal) = (
+        Point(offset.0 / distance, offset.1 / distance),
+        Point(-offset.1 / distance, offset.0 / distance),
+    );
+    let middle = a.center.offset(unit.0 * along, unit.1 * along);
+
+    if side < EPSILON {
+        return vec![middle];
+    }
+
+    vec![
+        middle.offset(normal.0 * side, normal.1 * side),
+        middle.offset(-normal.0 * side, -normal.1 * side),
+    ]
+}
+
+/// The points where the outline of a circle crosses the outline of a rectangle.
+pub fn circle_rectangle(circle: &Circle, rect: &Rectangle) -> Vec<Point> {
+    unique(
+        rectangle_edges(rect)
+            .iter()
+            .flat_map(|edge| edge_circle(edge, circle))
+            .collect(),
+    )
+}
+
+/// The points where the outlines of two rectangles cross or touch.
+pub fn rectangle_rectangle(a: &Rectangle, b: &Rectangle) -> Vec<Point> {
+    edges_edges(&rectangle_edges(a), &rectangle_edges(b))
+}
+
+/**
+The points where a path crosses a line segment, ordered by how far they are from the start
+of the line. Useful to stop a growing line exactly where it would hit another.
+
+The path is followed as a line, so the last point is only connected back to the first if
+the path is closed, and curved paths are flattened.
+*/
+pub fn path_segment(path: &Path, line: &Line) -> Vec<Point> {
+    sorted_from(
+        &line.start,
+        edges_edges(&path_edges(path), &[(line.start, line.end)]),
+    )
+}
+
+/// The points where a path crosses or touches the outline of a circle, see [`path_segment`].
+pub fn path_circle(path: &Path, circle: &Circle) -> Vec<Point> {
+    unique(
+        path_edges(path)
+            .iter()
+            .flat_map(|edge| edge_circle(edge, circle))
+            .collect(),
+    )
+}
+
+/// The points where a path crosses the outline of a rectangle, see [`path_segment`].
+pub fn path_rectangle(path: &Path, rect: &Rectangle) -> Vec<Point> {
+    edges_edges(&path_edges(path), &rectangle_edges(rect))
+}
+
+/// The points where two paths cross or touch, see [`path_segment`].
+pub fn path_path(a: &Path, b: &Path) -> Vec<Point> {
+    edges_edges(&path_edges(a), &path_edges(b))
+}
+
+/**
+The points where a path crosses itself, or touches itself. Neighboring lines of a sub-path
+that only meet at the point they share are not counted, which includes the last and the first
+line of a sub-path that ends where it starts, so a simple path, e.g. the outline of a convex
+shape, has no self intersections. Any other lines that meet are, even if it is only at their
+end points, like a path that goes through the same point twice.
+
+Example
+```
+use generative_art::{
+    geometry::intersection::self_intersections,
+    shapes::{path::Path, point::Point},
+};
+
+let bow_tie = Path::new(
+    vec![
+        Point(0.0, 0.0),
+        Point(10.0, 10.0),
+        Point(10.0, 0.0),
+        Point(0.0, 10.0),
+        Point(0.0, 0.0),
+    ],
+    Default::default(),
+);
+
+assert_eq!(self_intersections(&bow_tie), vec![Point(5.0, 5.0)]);
+```
+*/
+pub fn self_intersections(path: &Path) -> Vec<Point> {
+    let lines = line_edges(path);
+    let edges: Vec<(usize, usize, Edge)> = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(l, line)| line.iter().enumerate().map(move |(n, edge)| (l, n, *edge)))
+        .collect();
+    let mut found = vec![];
+
+    for i in 0..edges.len() {
+        for j in (i + 1)..edges.len() {
+            let ((l, m, e), (k, n, f)) = (&edges[i], &edges[j]);
+            if !boxes_overlap(e, f) {
+                continue;
+            }
+
+            let line = &lines[*l];
+            let closed = line.len() > 2 && key(&line[0].0) == key(&line[line.len() - 1].1);
+            let neighbors = l == k && (*n == m + 1 || (closed && *m == 0 && *n == line.len() - 1));
+
+            // Points that neighboring edges share as end points are where the path simply continues
+            let shared = |point: &Point| {
+                neighbors
+                    && [e.0, e.1].iter().any(|a| key(a) == key(point))
+                    && [f.0, f.1].iter().any(|b| key(b) == key(point))
+            };
+
+            found.extend(edge_edge(e, f).into_iter().filter(|point| !shared(point)));
+        }
+    }
+
+    unique(found)
+}
+
+fn edge_edge(e: &Edge, f: &Edge) -> Vec<Point> {
+    if !boxes_overlap(e, f) {
+        return vec![];
+    }
+
+    unique(
+        segment_intersections(e, f)
+            .into_iter()
+            .map(|(_, _, point)| point)
+            .collect(),
+    )
+}
+
+fn edges_edges(a: &[Edge], b: &[Edge]) -> Vec<Point> {
+    unique(
+        a.iter()
+            .flat_map(|e| b.iter().flat_map(move |f| edge_edge(e, f)))
+            .collect(),
+    )
+}
+
+/// Solve for where the line through the edge is exactly {radius} away from the center
+fn edge_circle(edge: &Edge, circle: &Circle) -> Vec<Point> {
+    let d = difference_of(&edge.1, &edge.0);
+    let f = difference_of(&edge.0, &circle.center);
+    let a = dot(&d, &d);
+    if a < EPSILON {
+        return vec![];
+    }
+
+    let b = 2.0 * dot(&f, &d);
+    let c = dot(&f, &f) - circle.radius.powi(2);
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < -EPSILON {
+        return vec![];
+    }
+
+    let root = discriminant.max(0.0).sqrt();
+    let at = |t: f64| Point(edge.0 .0 + d.0 * t, edge.0 .1 + d.1 * t);
+
+    unique(
+        [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+            .into_iter()
+            .filter(|t| (-EPSILON..=1.0 + EPSILON).contains(t))
+            .map(at)
+            .collect(),
+    )
+}
+
+fn rectangle_edges(rect: &Rectangle) -> Vec<Edge> {
+    let (x1, y1) = (rect.position.0, rect.position.1);
+    let (x2, y2) = (x1 + rect.width, y1 + rect.height);
+    let corners = [Point(x1, y1), Point(x2, y1), Point(x2, y2), Point(x1, y2)];
+
+    (0..4).map(|i| (corners[i], corners[(i + 1) % 4])).collect()
+}
+
+/// The lines of a path, without the jumps between sub-paths
+fn path_edges(path: &Path) -> Vec<Edge> {
+    line_edges(path).concat()
+}
+
+/// The edges of each sub-path of {path}, leaving out edges without length
+fn line_edges(path: &Path) -> Vec<Vec<Edge>> {
+    path.flatten(0.1)
+        .lines()
+        .into_iter()
+        .map(|line| {
+            line.windows(2)
+                .map(|pair| (pair[0], pair[1]))
+                .filter(|(a, b)| a.distance_to(b) > EPSILON)
+                .collect()
+        })
+        .collect()
+}
+
+/// Drop points that are at the same position as an earlier point
+fn unique(points: Vec<Point>) -> Vec<Point> {
+    let mut result: Vec<Point> = vec![];
+
+    for point in points {
+        if !result.iter().any(|p| key(p) == key(&point)) {
+            result.push(point);
+        }
+    }
+
+    result
+}
+
+fn sorted_from(origin: &Point, mut points: Vec<Point>) -> Vec<Point> {
+    points.sort_by(|a, b| origin.distance_to(a).total_cmp(&origin.distance_to(b)));
+    points
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        geometry::polygon::{cross, difference_of},
+        shapes::{circle::Circle, line::Line, path::Path, point::Point, rectangle::Rectangle},
+    };
+
+    use super::{
+        circle_circle, circle_rectangle, path_path, path_segment, segment_circle, segment_segment,
+        self_intersections,
+    };
+
+    /// True if {point} lies on the line through {a} and {b}
+    fn on_line(point: &Point, a: Point, b: Point) -> bool {
+        cross(&difference_of(&b, &a), &difference_of(point, &a)).abs() < 1e-6
+    }
+
+    fn line(a: Point, b: Point) -> Line {
+        Line::new(a, b, Default::default())
+    }
+
+    #[test]
+    fn segments() {
+        let a = line(Point(0., 0.), Point(10., 0.));
+
+        assert!(segment_segment(&a, &line(Point(0., 1.), Point(10., 1.))).is_empty());
+        assert!(segment_segment(&a, &line(Point(5., 1.), Point(5., 5.))).is_empty());
+        assert_eq!(
+            segment_segment(&a, &line(Point(5., 0.), Point(15., 0.))),
+            vec![Point(5., 0.), Point(10., 0.)]
+        );
+    }
+
+    #[test]
+    fn circles() {
+        let circle = Circle::new(Point(0., 0.), 5.);
+        let crossing = segment_circle(&line(Point(-10., 3.), Point(10., 3.)), &circle);
+
+        assert_eq!(crossing, vec![Point(-4., 3.), Point(4., 3.)]);
+
+        let other = Circle::new(Point(8., 0.), 5.);
+        let points = circle_circle(&circle, &other);
+        assert_eq!(points.len(), 2);
+        assert!(points
+            .iter()
+            .all(|p| on_line(p, Point(4., 0.), Point(4., 1.))));
+
+        let touching = Circle::new(Point(10., 0.), 5.);
+        assert_eq!(circle_circle(&circle, &touching), vec![Point(5., 0.)]);
+
+        let rect = Rectangle::new(Point(0., -10.), 10., 20.);
+        assert_eq!(
+            circle_rectangle(&circle, &rect),
+            vec![Point(0., 5.), Point(0., -5.)]
+        );
+    }
+
+    #[test]
+    fn paths() {
+        let zigzag = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(10., 10.),
+                Point(20., 0.),
+                Point(30., 10.),
+            ],
+            Default::default(),
+        );
+        let across = line(Point(30., 5.), Point(0., 5.));
+
+        assert_eq!(
+            path_segment(&zigzag, &across),
+            vec![Point(25., 5.), Point(15., 5.), Point(5., 5.)]
+        );
+        assert_eq!(
+            path_path(
+                &zigzag,
+                &Path::new(vec![Point(0., 5.), Point(10., 5.)], Default::default())
+            ),
+            vec![Point(5., 5.)]
+        );
+        assert!(self_intersections(&zigzag).is_empty());
+    }
+
+    #[test]
+    fn closed_path_is_not_self_intersecting() {
+        let square = Rectangle::new(Point(0., 0.), 10., 10.).to_path(Default::default());
+
+        assert!(self_intersections(&square).is_empty());
+    }
+    #[test]
+    fn path_through_the_same_point_twice() {
+        let path = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(10., 10.),
+                Point(20., 20.),
+                Point(20., 0.),
+                Point(10., 10.),
+                Point(0., 20.),
+            ],
+            Default::default(),
+        );
+
+        assert_eq!(self_intersections(&path), vec![Point(10., 10.)]);
+    }
+}
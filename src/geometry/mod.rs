@@ -0,0 +1,35 @@
+/// Union, intersection, difference and xor of closed shapes
+pub mod boolean;
+
+/// Isolines of scalar fields, using marching squares
+pub mod contour;
+
+/// Space-filling curves that fit a rectangle
+pub mod curves;
+
+/// Delaunay triangulations and Voronoi diagrams
+pub mod delaunay;
+
+/// Convex hulls and the smallest shapes around a set of points
+pub mod hull;
+
+/// Points where lines, circles, rectangles and paths cross
+pub mod intersection;
+
+/// Grow or shrink shapes and outline lines
+pub mod offset;
+
+/// Fill shapes with circles that don't overlap
+pub mod packing;
+
+/// Random points spread out inside shapes
+pub mod sampling;
+
+/// Signed distance fields built from shapes
+pub mod sdf;
+
+/// Split shapes up into triangles
+pub mod triangulate;
+
+/// Shared helpers for working with polygons as lists of rings
+pub(crate) mod polygon;
@@ -0,0 +1,282 @@
+use crate::shapes::{path::Path, point::Point};
+
+use super::polygon::{assemble, normalize, outline, EPSILON};
+
+/// How the corners are drawn where an offset turns outwards, like `stroke-linejoin` in SVG.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Join {
+    /// Extend the edges until they meet in a sharp corner. Corners that would reach further
+    /// than the given limit, times the offset distance, are beveled instead, like
+    /// `stroke-miterlimit` in SVG, which defaults to 4.
+    Miter(f64),
+
+    /// Round each corner off with an arc
+    Round,
+
+    /// Cut each corner off with a straight line
+    Bevel,
+}
+
+/**
+Grow a path outwards by {distance}, or shrink it if {distance} is negative. Parts of the
+offset that cross over themselves are removed, so shrinking a shape can split it into several
+parts, or make it disappear completely. Holes shrink when the shape grows, and the other
+way around.
+
+Every ring of the path is treated as closed, whether or not its last point is the same as the
+first, just like [`Shape::contains`](crate::shapes::shape::Shape::contains) and
+[`Shape::area`](crate::shapes::shape::Shape::area) do. Use [`offset_line`] to get the outline
+around a path as a line instead.
+
+Example
+```
+use generative_art::{
+    geometry::offset::{offset, Join},
+    shapes::{point::Point, rectangle::Rectangle},
+};
+
+let square = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0).to_path(Default::default());
+let echoes: Vec<_> = (1..5)
+    .flat_map(|i| offset(&square, i as f64 * -10.0, Join::Miter(4.0)))
+    .collect();
+
+assert_eq!(echoes.len(), 4);
+```
+*/
+pub fn offset(path: &Path, distance: f64, join: Join) -> Vec<Path> {
+    if distance == 0.0 {
+        return vec![path.clone()];
+    }
+
+    offset_rings(&normalize(path), distance, join, path)
+}
+
+/**
+The outline of {path} as a line, {distance} away on both sides of it, with the ends cut flat
+for [`Join::Miter`] and [`Join::Bevel`], and rounded for [`Join::Round`]. Each sub-path is
+treated as a line of its own, even when it ends where it starts.
+
+Example
+```
+use generative_art::{
+    geometry::offset::{offset_line, Join},
+    shapes::{path::Path, point::Point},
+};
+
+let line = Path::new(vec![Point(0.0, 0.0), Point(100.0, 0.0)], Default::default());
+let stroke = offset_line(&line, 5.0, Join::Round);
+
+assert_eq!(stroke.len(), 1);
+```
+*/
+pub fn offset_line(path: &Path, distance: f64, join: Join) -> Vec<Path> {
+    // Walk along each line and back again, which makes a closed ring without area
+    // whose outwards offset is the outline of the line.
+    let rings: Vec<Vec<Point>> = path
+        .flatten(0.1)
+        .lines()
+        .into_iter()
+        .map(|mut points| {
+            points.dedup_by(|a, b| a.distance_to(b) < EPSILON);
+
+            let back: Vec<Point> = points
+                .iter()
+                .rev()
+                .skip(1)
+                .take(points.len().saturating_sub(2))
+                .copied()
+                .collect();
+            points.extend(back);
+            points
+        })
+        .collect();
+
+    offset_rings(&rings, distance.abs(), join, path)
+}
+
+/// Offset each of the counter-clockwise {rings}, and keep the parts that end up outside.
+fn offset_rings(rings: &[Vec<Point>], distance: f64, join: Join, path: &Path) -> Vec<Path> {
+    let raw: Vec<Vec<Point>> = rings
+        .iter()
+        .filter(|ring| ring.len() > 1)
+        .map(|ring| offset_ring(ring, distance, join))
+        .collect();
+
+    assemble(outline(&raw, |winding| winding > 0), path.style)
+}
+
+/// Move every edge of a counter-clockwise ring {distance} to its right, joining up the corners.
+/// The result crosses over itself in places where edges overlap once they have been moved.
+fn offset_ring(ring: &[Point], distance: f64, join: Join) -> Vec<Point> {
+    let count = ring.len();
+    let mut points = vec![];
+
+    for i in 0..count {
+        let previous = ring[(i + count - 1) % count];
+        let current = ring[i];
+        let next = ring[(i + 1) % count];
+
+        let n1 = right_normal(&previous, &current);
+        let n2 = right_normal(&current, &next);
+        let a = current.offset(n1.0 * distance, n1.1 * distance);
+        let b = current.offset(n2.0 * distance, n2.1 * distance);
+
+        let turn = n1.0 * n2.1 - n1.1 * n2.0;
+        let alignment = n1.0 * n2.0 + n1.1 * n2.1;
+        let reverses = alignment < -1.0 + EPSILON;
+
+        if a.distance_to(&b) < EPSILON {
+            points.push(a);
+        } else if turn * distance > 0.0 || reverses {
+            // The edges move apart at this corner, fill the gap with a join
+            match join {
+                Join::Miter(limit) if !reverses => {
+                    let scale = distance / (1.0 + alignment);
+                    let miter = current.offset((n1.0 + n2.0) * scale, (n1.1 + n2.1) * scale);
+
+                    if miter.distance_to(&current) <= limit * distance.abs() {
+                        points.push(miter);
+                    } else {
+                        points.push(a);
+                        points.push(b);
+                    }
+                }
+                Join::Round => {
+                    let angle = if reverses {
+                        std::f64::consts::PI * distance.signum()
+                    } else {
+                        turn.atan2(alignment)
+                    };
+
+                    let step = 2.0 * (1.0 - (0.1 / distance.abs()).min(1.0)).acos();
+                    let steps = (angle.abs() / step).ceil().max(1.0) as usize;
+
+                    for s in 0..=steps {
+                        let (sin, cos) = (angle * s as f64 / steps as f64).sin_cos();
+                        let normal = Point(n1.0 * cos - n1.1 * sin, n1.0 * sin + n1.1 * cos);
+                        points.push(current.offset(normal.0 * distance, normal.1 * distance));
+                    }
+                }
+                _ => {
+                    points.push(a);
+                    points.push(b);
+                }
+            }
+        } else {
+            // The edges overlap at this corner, going through the corner itself makes
+            // a small loop that is removed once the outline is traced.
+            points.push(a);
+            points.push(current);
+            points.push(b);
+        }
+    }
+
+    points
+}
+
+/// Unit vector pointing to the right of the line going from {a} to {b}, which is
+/// outwards for a counter-clockwise ring.
+fn right_normal(a: &Point, b: &Point) -> Point {
+    let length = a.distance_to(b);
+
+    Point((b.1 - a.1) / length, -(b.0 - a.0) / length)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        geometry::polygon::signed_area,
+        shapes::{path::Path, point::Point, rectangle::Rectangle, shape::Shape},
+    };
+
+    use super::{offset, offset_line, Join};
+
+    fn area(paths: &[Path]) -> f64 {
+        paths
+            .iter()
+            .flat_map(|path| path.rings())
+            .map(|ring| signed_area(&ring) / 2.0)
+            .sum()
+    }
+
+    fn square() -> Path {
+        Rectangle::new(Point(0., 0.), 10., 10.).to_path(Default::default())
+    }
+
+    #[test]
+    fn grow_square() {
+        assert!((area(&offset(&square(), 1., Join::Miter(4.))) - 144.).abs() < 1e-6);
+        assert!((area(&offset(&square(), 1., Join::Bevel)) - 142.).abs() < 1e-6);
+
+        // The arcs are polygons, so slightly smaller than true circles
+        let round = area(&offset(&square(), 1., Join::Round));
+        assert!(round > 142. && round < 140. + std::f64::consts::PI);
+    }
+
+    #[test]
+    fn shrink_square() {
+        let shrunk = offset(&square(), -2., Join::Round);
+
+        assert_eq!(shrunk.len(), 1);
+        assert!((area(&shrunk) - 36.).abs() < 1e-6);
+        assert!(offset(&square(), -6., Join::Miter(4.)).is_empty());
+    }
+
+    #[test]
+    fn grow_concave_shape() {
+        let l_shape = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(10., 0.),
+                Point(10., 5.),
+                Point(5., 5.),
+                Point(5., 10.),
+                Point(0., 10.),
+                Point(0., 0.),
+            ],
+            Default::default(),
+        );
+
+        let grown = offset(&l_shape, 1., Join::Miter(4.));
+
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown[0].rings()[0].len(), 6);
+        assert!((area(&grown) - 119.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn outline_open_line() {
+        let line = Path::new(vec![Point(0., 0.), Point(10., 0.)], Default::default());
+
+        assert!((area(&offset_line(&line, 1., Join::Bevel)) - 20.).abs() < 1e-6);
+        assert!(offset(&line, 1., Join::Bevel).is_empty());
+
+        let round = area(&offset_line(&line, 1., Join::Round));
+        assert!(round > 22. && round < 20. + std::f64::consts::PI);
+
+        // A band around the square, with a notch where both ends of the line are cut off
+        let band = offset_line(&square(), 1., Join::Miter(4.));
+        assert_eq!(band[0].rings().len(), 2);
+        assert!((area(&band) - 79.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rings_are_closed() {
+        let open = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(100., 0.),
+                Point(100., 100.),
+                Point(0., 100.),
+            ],
+            Default::default(),
+        );
+        let mut closed = open.clone();
+        closed.add_point(Point(0., 0.));
+
+        assert!(open.contains(&Point(50., 50.)));
+        for path in [open, closed] {
+            assert!((area(&offset(&path, 10., Join::Miter(4.))) - 14400.).abs() < 1e-6);
+        }
+    }
+}
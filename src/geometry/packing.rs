@@ -0,0 +1,529 @@
+use std::fmt::Debug;
+
+use rand::Rng;
+
+use crate::shapes::{
+    circle::Circle, point::Point, pointmap::PointMap, rectangle::Rectangle, shape::Shape,
+};
+
+use super::{
+    intersection::circle_circle,
+    polygon::{cross, difference_of},
+};
+
+/**
+Settings for [`pack_circles`].
+
+Example
+```
+use generative_art::geometry::packing::{PackConfig, PackMethod, RadiusDistribution};
+
+let config = PackConfig {
+    min_radius: 1.0,
+    max_radius: 30.0,
+    distribution: RadiusDistribution::Power(3.0),
+    padding: Box::new(|point| point.0 / 100.0),
+    method: PackMethod::FrontChain,
+    ..Default::default()
+};
+```
+*/
+pub struct PackConfig {
+    /// The smallest circle that is placed
+    pub min_radius: f64,
+
+    /// The largest circle that is placed
+    pub max_radius: f64,
+
+    /// How the radius of each circle is picked between {min_radius} and {max_radius}
+    pub distribution: RadiusDistribution,
+
+    /// The space to leave between circles, depending on where the circle is placed
+    pub padding: Box<dyn Fn(&Point) -> f64>,
+
+    /// The most circles to try to place before giving up
+    pub attempts: usize,
+
+    /// How circles are placed
+    pub method: PackMethod,
+}
+
+/// How the radius of each circle is picked
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RadiusDistribution {
+    /// Every radius is as likely as any other
+    #[default]
+    Uniform,
+
+    /// A uniform value raised to the given power, above 1 favors small circles
+    /// and below 1 favors large circles
+    Power(f64),
+}
+
+/// How circles are placed by [`pack_circles`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PackMethod {
+    /// Try random positions, and make each circle as large as it can be at that position,
+    /// but never larger than a randomly picked radius. Gives a loose, organic packing
+    /// where the largest circles are placed first.
+    #[default]
+    Random,
+
+    /// Grow a tight packing outwards from a point inside the region, placing each new
+    /// circle so that it touches two others along the outside of the packing. Follows
+    /// "Visualization of large hierarchical data by circle packing" by Wang et al.
+    FrontChain,
+}
+
+impl Default for PackConfig {
+    fn default() -> Self {
+        PackConfig {
+            min_radius: 2.0,
+            max_radius: 20.0,
+            distribution: RadiusDistribution::Uniform,
+            padding: Box::new(|_| 0.0),
+            attempts: 5000,
+            method: PackMethod::Random,
+        }
+    }
+}
+
+impl Debug for PackConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PackConfig")
+            .field("min_radius", &self.min_radius)
+            .field("max_radius", &self.max_radius)
+            .field("distribution", &self.distribution)
+            .field("attempts", &self.attempts)
+            .field("method", &self.method)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PackConfig {
+    fn radius<R: Rng>(&self, rng: &mut R) -> f64 {
+        let (min, max) = (self.min_radius.min(self.max_radius), self.max_radius);
+        let t = rng.gen_range(0.0..1.0);
+        let t = match self.distribution {
+            RadiusDistribution::Uniform => t,
+            RadiusDistribution::Power(power) => f64::powf(t, power),
+        };
+
+        min + (max - min).max(0.0) * t
+    }
+}
+
+/**
+Fill {region} with circles that don't overlap each other and stay inside the region, see
+[`PackConfig`] for the settings. Circles are returned in the order they were placed.
+
+Example
+```
+use generative_art::{
+    geometry::packing::{pack_circles, PackConfig},
+    shapes::{circle::Circle, point::Point, rectangle::Rectangle},
+    svg::document::Document,
+};
+
+let bounds = Rectangle::new(Point(0.0, 0.0), 200.0, 200.0);
+let region = Circle::new(Point(100.0, 100.0), 90.0);
+let circles = pack_circles(&region, &PackConfig::default(), &mut rand::thread_rng());
+
+let mut document = Document::new("Packing", bounds);
+for circle in circles {
+    document.add_shape(Box::new(circle));
+}
+```
+*/
+pub fn pack_circles<R: Rng>(region: &dyn Shape, config: &PackConfig, rng: &mut R) -> Vec<Circle> {
+    let Some(bounds) = region.bounding_box() else {
+        return vec![];
+    };
+
+    if config.max_radius <= 0.0 || bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return vec![];
+    }
+
+    match config.method {
+        PackMethod::Random => pack_random(region, &bounds, config, rng),
+        PackMethod::FrontChain => pack_front_chain(region, &bounds, config, rng),
+    }
+}
+
+fn pack_random<R: Rng>(
+    region: &dyn Shape,
+    bounds: &Rectangle,
+    config: &PackConfig,
+    rng: &mut R,
+) -> Vec<Circle> {
+    let mut placed = Circles::new(bounds, config);
+
+    for _ in 0..config.attempts {
+        let center = Point(
+            rng.gen_range(bounds.x_range()),
+            rng.gen_range(bounds.y_range()),
+        );
+        let padding = (config.padding)(&center);
+
+        // The largest circle that fits at this position
+        let mut radius = config.radius(rng).min(-region.distance_to(&center));
+        for other in placed.near(&center, radius, padding) {
+            radius = radius.min(center.distance_to(&other.center) - other.radius - padding);
+        }
+
+        if radius >= config.min_radius && radius > 0.0 {
+            placed.insert(Circle::new(center, radius));
+        }
+    }
+
+    placed.circles
+}
+
+fn pack_front_chain<R: Rng>(
+    region: &dyn Shape,
+    bounds: &Rectangle,
+    config: &PackConfig,
+    rng: &mut R,
+) -> Vec<Circle> {
+    let origin = seed(region, bounds, rng);
+
+    // The packing grows round until it covers the corners of the bounds, the circles on the
+    // outside of it go past the bounds, so they are kept in a map that is large enough.
+    let extent = [
+        bounds.position,
+        bounds.position.offset(bounds.width, 0.0),
+        bounds.position.offset(0.0, bounds.height),
+        bounds.position.offset(bounds.width, bounds.height),
+    ]
+    .iter()
+    .map(|corner| corner.distance_to(&origin))
+    .fold(0.0, f64::max)
+        + config.max_radius * 2.0;
+    let reach = extent + config.max_radius * 4.0;
+    let area = Rectangle::new(origin.offset(-reach, -reach), reach * 2.0, reach * 2.0);
+    let mut placed = Circles::new(&area, config);
+
+    // Circles are laid out with half of the padding added to their radius, so that two
+    // touching circles end up with the full padding between them.
+    let mut radii: Vec<f64> = vec![];
+    let add = |center: Point,
+               (radius, padding): (f64, f64),
+               placed: &mut Circles<'_>,
+               radii: &mut Vec<f64>| {
+        placed.insert(Circle::new(center, radius + padding / 2.0));
+        radii.push(radius);
+        radii.len() - 1
+    };
+
+    let pick = |at: &Point, rng: &mut R| (config.radius(rng), (config.padding)(at).max(0.0));
+
+    // Start with three circles that touch each other around the center
+    let (a, b, c) = (pick(&origin, rng), pick(&origin, rng), pick(&origin, rng));
+    let (ra, rb) = (a.0 + a.1 / 2.0, b.0 + b.1 / 2.0);
+    let first = Circle::new(origin.offset(-rb, 0.0), ra);
+    let second = Circle::new(origin.offset(ra, 0.0), rb);
+    let Some(third) = tangent_circle(&first, &second, c.0 + c.1 / 2.0) else {
+        return vec![];
+    };
+
+    // The chain goes counter-clockwise around the outside of the packing
+    let mut chain = vec![
+        add(first.center, a, &mut placed, &mut radii),
+        add(third.center, c, &mut placed, &mut radii),
+        add(second.center, b, &mut placed, &mut radii),
+    ];
+
+    for _ in 3..config.attempts {
+        if chain.len() < 3 {
+            break;
+        }
+
+        // Grow from the circle on the chain that is closest to the center
+        let distance = |i: &usize| placed.circles[*i].center.distance_to(&origin);
+        let Some(closest) = chain
+            .iter()
+            .copied()
+            .min_by(|i, j| distance(i).total_cmp(&distance(j)))
+        else {
+            break;
+        };
+        if distance(&closest) > extent {
+            break;
+        }
+
+        let size = pick(&placed.circles[closest].center, rng);
+        let radius = size.0 + size.1 / 2.0;
+        let (mut m, mut n) = (closest, next(&chain, closest));
+
+        let center = loop {
+            let Some(circle) = tangent_circle(&placed.circles[m], &placed.circles[n], radius)
+            else {
+                break None;
+            };
+
+            // The closest circle along the chain that is in the way is placed against
+            // instead, and the circles between it and the one it replaces are cut out.
+            let count = chain.len();
+            let (pm, pn) = (position(&chain, m), position(&chain, n));
+            let blocking = (0..count)
+                .filter(|k| *k != pm && *k != pn)
+                .filter(|k| overlaps(&circle, &placed.circles[chain[*k]]))
+                .map(|k| {
+                    let after_n = (k + count - pn) % count;
+                    let before_m = (pm + count - k) % count;
+                    (after_n.min(before_m), after_n <= before_m, chain[k])
+                })
+                .min_by_key(|(steps, _, _)| *steps);
+
+            match blocking {
+                None => break Some(circle.center),
+                Some((_, true, k)) => {
+                    remove_between(&mut chain, m, k);
+                    n = k;
+                }
+                Some((_, false, k)) => {
+                    remove_between(&mut chain, k, n);
+                    m = k;
+                }
+            }
+
+            if chain.len() < 3 {
+                break None;
+            }
+        };
+
+        let Some(center) = center else {
+            break;
+        };
+
+        // The chain only knows about the outside of the packing, a circle that still
+        // overlaps one inside means the chain has folded in on itself around {m}.
+        if placed.overlaps(&Circle::new(center, radius)) {
+            chain.retain(|i| *i != m);
+            continue;
+        }
+
+        let index = add(center, size, &mut placed, &mut radii);
+        chain.insert(position(&chain, m) + 1, index);
+    }
+
+    // Circles outside of the region kept the chain growing, but are left out
+    placed
+        .circles
+        .iter()
+        .zip(radii)
+        .filter(|(circle, radius)| {
+            *radius >= config.min_radius && region.distance_to(&circle.center) <= -radius
+        })
+        .map(|(circle, radius)| Circle::new(circle.center, radius))
+        .collect()
+}
+
+/// The circles placed so far, in a [`PointMap`] so only the closest ones need to be checked
+struct Circles<'a> {
+    circles: Vec<Circle>,
+    map: PointMap<'a, Circle>,
+
+    /// Circles with their center outside of the bounds of {map}, which are always checked
+    outside: Vec<Circle>,
+
+    /// The largest radius placed so far
+    largest: f64,
+}
+
+impl<'a> Circles<'a> {
+    /// Circles placed within {bounds}, in cells about the size of the largest circle
+    fn new(bounds: &'a Rectangle, config: &PackConfig) -> Circles<'a> {
+        let size = config.max_radius * 2.0;
+        let resolution = (bounds.width.min(bounds.height) / size).floor().max(1.0) as usize;
+
+        Circles {
+            circles: vec![],
+            map: PointMap::new(bounds, resolution),
+            outside: vec![],
+            largest: 0.0,
+        }
+    }
+
+    fn insert(&mut self, circle: Circle) {
+        if let Err(circle) = self.map.insert(circle) {
+            self.outside.push(circle);
+        }
+        self.largest = self.largest.max(circle.radius);
+        self.circles.push(circle);
+    }
+
+    /// The circles that could be within {padding} of a circle with {radius} at {center}
+    fn near<'b>(
+        &'b self,
+        center: &'b Point,
+        radius: f64,
+        padding: f64,
+    ) -> impl Iterator<Item = &'b Circle> + 'b {
+        let reach = radius.max(0.0) + padding.max(0.0) + self.largest;
+
+        self.map
+            .within_radius(center, reach)
+            .chain(self.outside.iter())
+    }
+
+    fn overlaps(&self, circle: &Circle) -> bool {
+        self.near(&circle.center, circle.radius, 0.0)
+            .any(|other| overlaps(circle, other))
+    }
+}
+
+/// A point well inside {region} to grow a packing from. The center of the bounds when it is
+/// inside the region, otherwise the deepest of a number of random points within the bounds.
+fn seed<R: Rng>(region: &dyn Shape, bounds: &Rectangle, rng: &mut R) -> Point {
+    let center = bounds.center();
+    if region.distance_to(&center) < 0.0 {
+        return center;
+    }
+
+    (0..100)
+        .map(|_| {
+            Point(
+                rng.gen_range(bounds.x_range()),
+                rng.gen_range(bounds.y_range()),
+            )
+        })
+        .min_by(|a, b| region.distance_to(a).total_cmp(&region.distance_to(b)))
+        .unwrap_or(center)
+}
+
+/// A circle with {radius} that touches both {a} and {b}, on the right side when going
+/// from {a} to {b}, which is the outside of a chain going counter-clockwise.
+fn tangent_circle(a: &Circle, b: &Circle, radius: f64) -> Option<Circle> {
+    let around_a = Circle::new(a.center, a.radius + radius);
+    let around_b = Circle::new(b.center, b.radius + radius);
+    let direction = difference_of(&b.center, &a.center);
+
+    circle_circle(&around_a, &around_b)
+        .into_iter()
+        .min_by(|p, q| {
+            let side = |point: &Point| cross(&direction, &difference_of(point, &a.center));
+            side(p).total_cmp(&side(q))
+        })
+        .map(|center| Circle::new(center, radius))
+}
+
+/// True if the circles overlap by more than a rounding error
+fn overlaps(a: &Circle, b: &Circle) -> bool {
+    a.distance(b) < -1e-6
+}
+
+/// Remove the circles strictly between {from} and {to}, going forwards around the chain
+fn remove_between(chain: &mut Vec<usize>, from: usize, to: usize) {
+    let (start, count) = (position(chain, from), chain.len());
+    let steps = (position(chain, to) + count - start) % count;
+    let removed: Vec<usize> = (1..steps)
+        .map(|step| chain[(start + step) % count])
+        .collect();
+
+    chain.retain(|i| !removed.contains(i));
+}
+
+/// Where the circle {index} is in the chain
+fn position(chain: &[usize], index: usize) -> usize {
+    chain.iter().position(|i| *i == index).unwrap_or(0)
+}
+
+/// The circle after {index} in the chain
+fn next(chain: &[usize], index: usize) -> usize {
+    chain[(position(chain, index) + 1) % chain.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::shapes::{
+        circle::Circle, path::Path, point::Point, rectangle::Rectangle, shape::Shape,
+    };
+
+    use super::{pack_circles, PackConfig, PackMethod};
+
+    fn assert_packed(region: &dyn Shape, circles: &[Circle], padding: f64) {
+        for (i, a) in circles.iter().enumerate() {
+            assert!(region.distance_to(&a.center) <= -a.radius + 1e-6);
+
+            for b in &circles[i + 1..] {
+                assert!(a.distance(b) >= padding - 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn random_packing() {
+        let region = Circle::new(Point(100., 100.), 80.);
+        let config = PackConfig {
+            padding: Box::new(|_| 1.0),
+            ..Default::default()
+        };
+        let circles = pack_circles(&region, &config, &mut ChaCha8Rng::seed_from_u64(1));
+
+        assert!(circles.len() > 50);
+        assert!(circles
+            .iter()
+            .all(|c| c.radius >= config.min_radius && c.radius <= config.max_radius));
+        assert_packed(&region, &circles, 1.0);
+    }
+
+    #[test]
+    fn front_chain_packing() {
+        let region = Rectangle::new(Point(0., 0.), 200., 100.);
+        let config = PackConfig {
+            min_radius: 4.,
+            max_radius: 8.,
+            method: PackMethod::FrontChain,
+            ..Default::default()
+        };
+        let circles = pack_circles(&region, &config, &mut ChaCha8Rng::seed_from_u64(1));
+
+        assert_packed(&region, &circles, 0.0);
+
+        // Tightly packed circles cover well over half of the area
+        let covered: f64 = circles.iter().map(|c| c.area()).sum();
+        assert!(covered > region.area() * 0.6);
+    }
+
+    #[test]
+    fn packing_away_from_origin() {
+        let rectangle = Rectangle::new(Point(300., 200.), 120., 80.);
+
+        // An L shape, the center of its bounding box is outside of it
+        let path = Path::from_rings(
+            vec![vec![
+                Point(500., 500.),
+                Point(600., 500.),
+                Point(600., 530.),
+                Point(530., 530.),
+                Point(530., 600.),
+                Point(500., 600.),
+            ]],
+            Default::default(),
+        );
+        assert!(!path.contains(&path.center()));
+
+        let gap = 1.5;
+        for method in [PackMethod::Random, PackMethod::FrontChain] {
+            for region in [&rectangle as &dyn Shape, &path] {
+                let config = PackConfig {
+                    min_radius: 2.,
+                    max_radius: 6.,
+                    padding: Box::new(move |_| gap),
+                    method,
+                    ..Default::default()
+                };
+                let circles = pack_circles(region, &config, &mut ChaCha8Rng::seed_from_u64(3));
+
+                assert_packed(region, &circles, gap);
+
+                let covered: f64 = circles.iter().map(|c| c.area()).sum();
+                assert!(covered > region.area() * 0.3, "{method:?} {covered}");
+            }
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+
+use crate::shapes::{
+    path::{Path, PathStyle},
+    point::Point,
+};
+
+/// Points closer together than this are considered to be the same point.
+pub(crate) const EPSILON: f64 = 1e-9;
+
+/**
+The winding number of {point} for a set of rings, i.e, how many times the rings wind
+around the point, counter-clockwise turns counting up and clockwise turns counting down.
+It is 0 for points outside of all rings.
+*/
+pub(crate) fn winding_number(rings: &[Vec<Point>], point: &Point) -> i32 {
+    let mut winding = 0;
+
+    for ring in rings {
+        for i in 0..ring.len() {
+            let (a, b) = (&ring[i], &ring[(i + 1) % ring.len()]);
+            let side = cross(&difference_of(b, a), &difference_of(point, a));
+
+            if a.1 <= point.1 {
+                if b.1 > point.1 && side > 0.0 {
+                    winding += 1;
+                }
+            } else if b.1 <= point.1 && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+
+    winding
+}
+
+/// Twice the signed area of a ring, positive for counter-clockwise rings when the y axis points up.
+pub(crate) fn signed_area(ring: &[Point]) -> f64 {
+    (0..ring.len())
+        .map(|i| cross(&ring[i], &ring[(i + 1) % ring.len()]))
+        .sum()
+}
+
+/// Get the rings of a path, with outlines turning counter-clockwise
+/// and holes turning clockwise.
+pub(crate) fn normalize(path: &Path) -> Vec<Vec<Point>> {
+    let mut rings: Vec<Vec<Point>> = path
+        .flatten(0.1)
+        .rings()
+        .into_iter()
+        .map(|mut ring| {
+            ring.dedup_by(|a, b| a.distance_to(b) < EPSILON);
+            ring
+        })
+        .filter(|ring| ring.len() > 2)
+        .collect();
+
+    let depths: Vec<usize> = (0..rings.len())
+        .map(|i| {
+            (0..rings.len())
+                .filter(|j| *j != i && winding_number(&rings[*j..=*j], &rings[i][0]) != 0)
+                .count()
+        })
+        .collect();
+
+    for (ring, depth) in rings.iter_mut().zip(depths) {
+        let is_hole = depth % 2 == 1;
+        if (signed_area(ring) > 0.0) == is_hole {
+            ring.reverse();
+        }
+    }
+
+    rings
+}
+
+pub(crate) fn edges(rings: &[Vec<Point>]) -> Vec<Edge> {
+    rings
+        .iter()
+        .flat_map(|ring| (0..ring.len()).map(move |i| (ring[i], ring[(i + 1) % ring.len()])))
+        .collect()
+}
+
+/// Split every edge at every point where it crosses or touches an edge of the other shape,
+/// so that no edge crosses the outline of the other shape.
+pub(crate) fn split_edges(a: &[Edge], b: &[Edge]) -> (Vec<Edge>, Vec<Edge>) {
+    let mut cuts_a: Vec<Vec<(f64, Point)>> = vec![vec![]; a.len()];
+    let mut cuts_b: Vec<Vec<(f64, Point)>> = vec![vec![]; b.len()];
+
+    for (i, e) in a.iter().enumerate() {
+        for (j, f) in b.iter().enumerate() {
+            if !boxes_overlap(e, f) {
+                continue;
+            }
+
+            for (t, u, point) in segment_intersections(e, f) {
+                cuts_a[i].push((t, point));
+                cuts_b[j].push((u, point));
+            }
+        }
+    }
+
+    (cut(a, cuts_a), cut(b, cuts_b))
+}
+
+/// Split every edge at every point where it crosses or touches another edge in the same set.
+pub(crate) fn split_self(edges: &[Edge]) -> Vec<Edge> {
+    let mut cuts: Vec<Vec<(f64, Point)>> = vec![vec![]; edges.len()];
+
+    for (i, e) in edges.iter().enumerate() {
+        for (j, f) in edges.iter().enumerate().skip(i + 1) {
+            if !boxes_overlap(e, f) {
+                continue;
+            }
+
+            for (t, u, point) in segment_intersections(e, f) {
+                cuts[i].push((t, point));
+                cuts[j].push((u, point));
+            }
+        }
+    }
+
+    cut(edges, cuts)
+}
+
+/**
+Find the outline of the area covered by a set of rings, that might cross themselves
+or each other. A point is covered if {filled} is true for its winding number. Each edge
+that has covered area on one side only is kept, turned so the covered area is on its left,
+and then linked back into rings.
+*/
+pub(crate) fn outline(rings: &[Vec<Point>], filled: impl Fn(i32) -> bool) -> Vec<Vec<Point>> {
+    let kept = split_self(&edges(rings))
+        .into_iter()
+        .filter_map(|(from, to)| {
+            let middle = from.between(&to, 0.5);
+            let length = from.distance_to(&to);
+            let normal = Point(
+                -(to.1 - from.1) / length * 1e-6,
+                (to.0 - from.0) / length * 1e-6,
+            );
+
+            let left = filled(winding_number(rings, &middle.offset(normal.0, normal.1)));
+            let right = filled(winding_number(rings, &middle.offset(-normal.0, -normal.1)));
+
+            match (left, right) {
+                (true, false) => Some((from, to)),
+                (false, true) => Some((to, from)),
+                _ => None,
+            }
+        })
+        .collect();
+
+    link(kept)
+}
+
+pub(crate) fn cut(edges: &[Edge], cuts: Vec<Vec<(f64, Point)>>) -> Vec<Edge> {
+    let mut result = vec![];
+
+    for ((from, to), mut cuts) in edges.iter().zip(cuts) {
+        cuts.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut previous = *from;
+        for point in cuts.into_iter().map(|(_, point)| point).chain([*to]) {
+            if key(&point) != key(&previous) {
+                result.push((previous, point));
+                previous = point;
+            }
+        }
+    }
+
+    result
+}
+
+/// Every point where the two segments meet, along with how far along each segment
+/// [0..1] the point is. Overlapping segments yield the end points of the overlap.
+pub(crate) fn segment_intersections(e: &Edge, f: &Edge) -> Vec<(f64, f64, Point)> {
+    let r = difference_of(&e.1, &e.0);
+    let s = difference_of(&f.1, &f.0);
+    let qp = difference_of(&f.0, &e.0);
+    let denominator = cross(&r, &s);
+    let lengths = dot(&r, &r).sqrt() * dot(&s, &s).sqrt();
+
+    if denominator.abs() > EPSILON * lengths {
+        let t = cross(&qp, &s) / denominator;
+        let u = cross(&qp, &r) / denominator;
+        let range = -EPSILON..=1.0 + EPSILON;
+
+        if !range.contains(&t) || !range.contains(&u) {
+            return vec![];
+        }
+
+        // Snap to existing end points so that both edges are cut at exactly the same point
+        let point = [(t, e.0), (1.0 - t, e.1), (u, f.0), (1.0 - u, f.1)]
+            .into_iter()
+            .find(|(distance, _)| distance.abs() < EPSILON)
+            .map(|(_, point)| point)
+            .unwrap_or(Point(e.0 .0 + t * r.0, e.0 .1 + t * r.1));
+
+        return vec![(t.clamp(0.0, 1.0), u.clamp(0.0, 1.0), point)];
+    }
+
+    if cross(&qp, &r).abs() > EPSILON * dot(&r, &r).sqrt() {
+        return vec![];
+    }
+
+    // Collinear, find the end points of each edge that lie on the other
+    let mut found = vec![];
+    let (rr, ss) = (dot(&r, &r), dot(&s, &s));
+    if rr == 0.0 || ss == 0.0 {
+        return found;
+    }
+
+    for (point, u) in [(f.0, 0.0), (f.1, 1.0)] {
+        let t = dot(&difference_of(&point, &e.0), &r) / rr;
+        if (0.0..=1.0).contains(&t) {
+            found.push((t, u, point));
+        }
+    }
+
+    for (point, t) in [(e.0, 0.0), (e.1, 1.0)] {
+        let u = dot(&difference_of(&point, &f.0), &s) / ss;
+        if (0.0..=1.0).contains(&u) {
+            found.push((t, u, point));
+        }
+    }
+
+    found
+}
+
+/// Join directed edges into closed rings by following each edge to one that starts where it ends.
+pub(crate) fn link(edges: Vec<Edge>) -> Vec<Vec<Point>> {
+    let mut outgoing: HashMap<Key, Vec<usize>> = HashMap::new();
+    for (i, (from, _)) in edges.iter().enumerate() {
+        outgoing.entry(key(from)).or_default().push(i);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = vec![];
+
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+
+        used[start] = true;
+        let mut ring = vec![edges[start].0];
+        let mut current = start;
+
+        loop {
+            let (from, to) = edges[current];
+            if key(&to) == key(&edges[start].0) {
+                rings.push(ring);
+                break;
+            }
+
+            // Where several edges leave from the same point, take the sharpest left turn
+            // to keep to the region that is being traced.
+            let incoming = difference_of(&to, &from);
+            let next = outgoing
+                .get(&key(&to))
+                .into_iter()
+                .flatten()
+                .filter(|i| !used[**i])
+                .max_by(|a, b| {
+                    let turn = |i: &usize| {
+                        let outgoing = difference_of(&edges[*i].1, &edges[*i].0);
+                        cross(&incoming, &outgoing).atan2(dot(&incoming, &outgoing))
+                    };
+                    turn(a).total_cmp(&turn(b))
+                })
+                .copied();
+
+            match next {
+                Some(next) => {
+                    used[next] = true;
+                    ring.push(to);
+                    current = next;
+                }
+                None => break,
+            }
+        }
+    }
+
+    rings
+        .into_iter()
+        .map(remove_collinear)
+        .filter(|ring| ring.len() > 2 && signed_area(ring).abs() > EPSILON)
+        .collect()
+}
+
+/// Group rings into paths, each outline together with the holes inside of it.
+pub(crate) fn assemble(rings: Vec<Vec<Point>>, style: PathStyle) -> Vec<Path> {
+    let (mut outlines, holes): (Vec<Vec<Point>>, Vec<Vec<Point>>) =
+        rings.into_iter().partition(|ring| signed_area(ring) > 0.0);
+    outlines.sort_by(|a, b| signed_area(a).total_cmp(&signed_area(b)));
+
+    let mut grouped: Vec<Vec<Vec<Point>>> = outlines.into_iter().map(|ring| vec![ring]).collect();
+
+    for hole in holes {
+        // A point just inside the hole, to the right of its first edge
+        let (a, b) = (hole[0], hole[1]);
+        let inside = a
+            .between(&b, 0.5)
+            .offset((b.1 - a.1) * 1e-6, -(b.0 - a.0) * 1e-6);
+
+        if let Some(group) = grouped
+            .iter_mut()
+            .find(|group| winding_number(&group[0..1], &inside) != 0)
+        {
+            group.push(hole);
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|rings| Path::from_rings(rings, style))
+        .collect()
+}
+
+pub(crate) fn remove_collinear(ring: Vec<Point>) -> Vec<Point> {
+    let mut result: Vec<Point> = vec![];
+
+    for i in 0..ring.len() {
+        let previous = result.last().copied().unwrap_or(ring[ring.len() - 1]);
+        let (current, next) = (ring[i], ring[(i + 1) % ring.len()]);
+        let a = difference_of(&current, &previous);
+        let b = difference_of(&next, &current);
+
+        if cross(&a, &b).abs() > EPSILON * (dot(&a, &a) + dot(&b, &b)) || dot(&a, &b) < 0.0 {
+            result.push(current);
+        }
+    }
+
+    result
+}
+
+pub(crate) fn boxes_overlap(e: &Edge, f: &Edge) -> bool {
+    e.0 .0.min(e.1 .0) <= f.0 .0.max(f.1 .0) + EPSILON
+        && f.0 .0.min(f.1 .0) <= e.0 .0.max(e.1 .0) + EPSILON
+        && e.0 .1.min(e.1 .1) <= f.0 .1.max(f.1 .1) + EPSILON
+        && f.0 .1.min(f.1 .1) <= e.0 .1.max(e.1 .1) + EPSILON
+}
+
+/// A directed line segment
+pub(crate) type Edge = (Point, Point);
+
+/// Points are matched on a fine grid to be forgiving with rounding errors.
+pub(crate) type Key = (i64, i64);
+
+pub(crate) fn key(point: &Point) -> Key {
+    (
+        (point.0 * 1e6).round() as i64,
+        (point.1 * 1e6).round() as i64,
+    )
+}
+
+pub(crate) fn difference_of(a: &Point, b: &Point) -> Point {
+    Point(a.0 - b.0, a.1 - b.1)
+}
+
+pub(crate) fn cross(a: &Point, b: &Point) -> f64 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+pub(crate) fn dot(a: &Point, b: &Point) -> f64 {
+    a.0 * b.0 + a.1 * b.1
+}
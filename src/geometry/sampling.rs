@@ -0,0 +1,403 @@
+use std::{f64::consts::TAU, ops::Range};
+
+use rand::Rng;
+
+use crate::shapes::{point::Point, rectangle::Rectangle, shape::Shape};
+
+use super::triangulate::triangulate;
+
+/**
+Pick {count} random points inside {shape}, spread out evenly over its area. The shape is
+split into triangles, each triangle is picked as often as its share of the area, and a point
+is then picked evenly within the triangle. Shapes without an area, like lines, give no points.
+
+Example
+```
+use generative_art::{
+    geometry::sampling::sample_uniform,
+    shapes::{circle::Circle, point::Point, shape::Shape},
+};
+
+let circle = Circle::new(Point(50.0, 50.0), 40.0);
+let starts = sample_uniform(&circle, 100, &mut rand::thread_rng());
+
+assert_eq!(starts.len(), 100);
+assert!(starts.iter().all(|point| circle.contains(point)));
+```
+*/
+pub fn sample_uniform<R: Rng>(shape: &dyn Shape, count: usize, rng: &mut R) -> Vec<Point> {
+    let area = Area::new(shape);
+
+    (0..count).filter_map(|_| area.pick(rng)).collect()
+}
+
+/**
+Pick up to {count} random points inside {shape}, where {density} decides how likely a point is
+to be kept, from 0 for never to 1 for always. Points are picked evenly with [`sample_uniform`]
+and then thinned out, so areas with a low density may take many tries. Fewer points are
+returned if {count} points could not be found within a thousand tries per point.
+
+Example
+```
+use generative_art::{
+    geometry::sampling::sample_weighted,
+    shapes::{point::Point, rectangle::Rectangle},
+};
+
+// More points towards the right
+let rect = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+let points = sample_weighted(&rect, 100, |point| point.0 / 100.0, &mut rand::thread_rng());
+```
+*/
+pub fn sample_weighted<R: Rng>(
+    shape: &dyn Shape,
+    count: usize,
+    density: impl Fn(&Point) -> f64,
+    rng: &mut R,
+) -> Vec<Point> {
+    let area = Area::new(shape);
+    let mut points = vec![];
+
+    for _ in 0..count * 1000 {
+        if points.len() == count {
+            break;
+        }
+
+        match area.pick(rng) {
+            Some(candidate) => {
+                if rng.gen_range(0.0..1.0) < density(&candidate).clamp(0.0, 1.0) {
+                    points.push(candidate);
+                }
+            }
+            None => break,
+        }
+    }
+
+    points
+}
+
+/**
+Pick random points inside {shape} that are at least {distance} apart, but otherwise fill the
+shape evenly, using Bridson's Poisson-disk sampling. Unlike [`sample_uniform`] there are no
+clumps or large gaps between the points.
+
+Example
+```
+use generative_art::{
+    geometry::sampling::poisson_disk,
+    shapes::{point::Point, rectangle::Rectangle},
+};
+
+let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+let points = poisson_disk(&bounds, 5.0, &mut rand::thread_rng());
+
+assert!(points
+    .iter()
+    .all(|a| points.iter().all(|b| a == b || a.distance_to(b) >= 5.0)));
+```
+*/
+pub fn poisson_disk<R: Rng>(shape: &dyn Shape, distance: f64, rng: &mut R) -> Vec<Point> {
+    poisson_disk_variable(shape, distance..distance, |_| distance, rng)
+}
+
+/**
+Like [`poisson_disk`], but the distance between points changes over the shape. {distance}
+gives the spacing around a point, e.g. from a noise field, the brightness of an image or the
+distance to a point, and is kept within {range}. Two points are at least the average of the
+spacing around each of them apart.
+
+Example
+```
+use generative_art::{
+    geometry::sampling::poisson_disk_variable,
+    shapes::{point::Point, rectangle::Rectangle},
+};
+
+// Points get further apart away from the center
+let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+let center = Point(50.0, 50.0);
+let points = poisson_disk_variable(
+    &bounds,
+    2.0..10.0,
+    |point| point.distance_to(&center) / 5.0,
+    &mut rand::thread_rng(),
+);
+```
+*/
+pub fn poisson_disk_variable<R: Rng>(
+    shape: &dyn Shape,
+    range: Range<f64>,
+    distance: impl Fn(&Point) -> f64,
+    rng: &mut R,
+) -> Vec<Point> {
+    /// Candidates to try around each point before it is done
+    const TRIES: usize = 30;
+
+    let (min, max) = (range.start.min(range.end), range.start.max(range.end));
+    let Some(bounds) = shape.bounding_box() else {
+        return vec![];
+    };
+    if min <= 0.0 || bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return vec![];
+    }
+
+    let spacing = |point: &Point| distance(point).clamp(min, max);
+    let mut grid = Grid::new(&bounds, min, max);
+    let mut active: Vec<usize> = vec![];
+
+    loop {
+        // Start from a random point, again whenever the points run out of room, to reach
+        // parts of the shape that are not connected to the rest.
+        if active.is_empty() {
+            let start = (0..TRIES)
+                .map(|_| {
+                    Point(
+                        rng.gen_range(bounds.x_range()),
+                        rng.gen_range(bounds.y_range()),
+                    )
+                })
+                .find(|point| shape.contains(point) && grid.fits(point, spacing(point)));
+
+            match start {
+                Some(point) => active.push(grid.insert(point, spacing(&point))),
+                None => break,
+            }
+        }
+
+        let slot = rng.gen_range(0..active.len());
+        let (around, radius) = grid.points[active[slot]];
+
+        let found = (0..TRIES).find_map(|_| {
+            let angle = rng.gen_range(0.0..TAU);
+            let length = rng.gen_range(radius..radius * 2.0);
+            let candidate = around.offset(angle.cos() * length, angle.sin() * length);
+            let candidate_spacing = spacing(&candidate);
+
+            (shape.contains(&candidate) && grid.fits(&candidate, candidate_spacing))
+                .then_some((candidate, candidate_spacing))
+        });
+
+        match found {
+            Some((point, radius)) => active.push(grid.insert(point, radius)),
+            None => {
+                active.swap_remove(slot);
+            }
+        }
+    }
+
+    grid.points.into_iter().map(|(point, _)| point).collect()
+}
+
+/// Points placed by [`poisson_disk_variable`] with the spacing around them, in a grid
+/// small enough that each cell holds at most one point
+struct Grid {
+    origin: Point,
+    cell: f64,
+    columns: usize,
+    rows: usize,
+    cells: Vec<Option<usize>>,
+    points: Vec<(Point, f64)>,
+
+    /// How many cells away a point that is too close can be
+    reach: isize,
+}
+
+impl Grid {
+    fn new(bounds: &Rectangle, min: f64, max: f64) -> Grid {
+        let cell = min / std::f64::consts::SQRT_2;
+        let columns = (bounds.width / cell).ceil() as usize + 1;
+        let rows = (bounds.height / cell).ceil() as usize + 1;
+
+        Grid {
+            origin: bounds.position,
+            cell,
+            columns,
+            rows,
+            cells: vec![None; columns * rows],
+            points: vec![],
+            reach: (max / cell).ceil() as isize,
+        }
+    }
+
+    fn cell_of(&self, point: &Point) -> (isize, isize) {
+        (
+            ((point.0 - self.origin.0) / self.cell).floor() as isize,
+            ((point.1 - self.origin.1) / self.cell).floor() as isize,
+        )
+    }
+
+    fn index(&self, column: isize, row: isize) -> Option<usize> {
+        (column >= 0 && row >= 0 && (column as usize) < self.columns && (row as usize) < self.rows)
+            .then(|| row as usize * self.columns + column as usize)
+    }
+
+    fn insert(&mut self, point: Point, spacing: f64) -> usize {
+        let (column, row) = self.cell_of(&point);
+        self.points.push((point, spacing));
+
+        if let Some(i) = self.index(column, row) {
+            self.cells[i] = Some(self.points.len() - 1);
+        }
+        self.points.len() - 1
+    }
+
+    /// True if {point} is far enough from every other point
+    fn fits(&self, point: &Point, spacing: f64) -> bool {
+        let (column, row) = self.cell_of(point);
+        if self.index(column, row).is_none() {
+            return false;
+        }
+
+        (-self.reach..=self.reach).all(|dy| {
+            (-self.reach..=self.reach).all(|dx| {
+                match self
+                    .index(column + dx, row + dy)
+                    .and_then(|i| self.cells[i])
+                {
+                    Some(other) => {
+                        let (other, other_spacing) = self.points[other];
+                        point.distance_to(&other) >= (spacing + other_spacing) / 2.0
+                    }
+                    None => true,
+                }
+            })
+        })
+    }
+}
+
+/// The area of a shape split up into triangles, to pick random points from
+struct Area {
+    triangles: Vec<[Point; 3]>,
+
+    /// The total area of all triangles up to and including each triangle
+    cumulative: Vec<f64>,
+}
+
+impl Area {
+    fn new(shape: &dyn Shape) -> Area {
+        let triangles = triangulate(&shape.to_polygon(0.1));
+
+        let mut total = 0.0;
+        let cumulative = triangles
+            .iter()
+            .map(|[a, b, c]| {
+                total += ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)).abs() / 2.0;
+                total
+            })
+            .collect();
+
+        Area {
+            triangles,
+            cumulative,
+        }
+    }
+
+    /// A random point within the area, or None if there is no area
+    fn pick<R: Rng>(&self, rng: &mut R) -> Option<Point> {
+        let total = *self.cumulative.last()?;
+        if total <= 0.0 {
+            return None;
+        }
+
+        let target = rng.gen_range(0.0..total);
+        let index = self
+            .cumulative
+            .partition_point(|area| *area <= target)
+            .min(self.triangles.len() - 1);
+        let [a, b, c] = self.triangles[index];
+
+        // Fold points from the far half of the parallelogram back into the triangle
+        let (mut u, mut v): (f64, f64) = (rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0));
+        if u + v > 1.0 {
+            u = 1.0 - u;
+            v = 1.0 - v;
+        }
+
+        Some(Point(
+            a.0 + u * (b.0 - a.0) + v * (c.0 - a.0),
+            a.1 + u * (b.1 - a.1) + v * (c.1 - a.1),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::shapes::{
+        circle::Circle, line::Line, point::Point, rectangle::Rectangle, shape::Shape,
+    };
+
+    use super::{poisson_disk, poisson_disk_variable, sample_uniform, sample_weighted};
+
+    #[test]
+    fn uniform_inside_shape() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let rect = Rectangle::new(Point(0., 0.), 100., 10.);
+        let points = sample_uniform(&rect, 1000, &mut rng);
+
+        assert_eq!(points.len(), 1000);
+        assert!(points.iter().all(|p| rect.contains(p)));
+
+        // Both halves get about the same number of points
+        let left = points.iter().filter(|p| p.0 < 50.).count();
+        assert!((400..600).contains(&left));
+    }
+
+    #[test]
+    fn weighted_by_density() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let rect = Rectangle::new(Point(0., 0.), 100., 100.);
+        let points = sample_weighted(&rect, 500, |p| if p.0 < 50. { 1. } else { 0.1 }, &mut rng);
+
+        let left = points.iter().filter(|p| p.0 < 50.).count();
+        assert_eq!(points.len(), 500);
+        assert!(left > 400);
+    }
+
+    #[test]
+    fn lines_have_no_inside() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let line = Line::new(Point(0., 0.), Point(10., 10.), Default::default());
+
+        assert!(sample_uniform(&line, 10, &mut rng).is_empty());
+        assert!(sample_weighted(&line, 10, |_| 1., &mut rng).is_empty());
+    }
+
+    #[test]
+    fn poisson_disk_fills_shape() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let circle = Circle::new(Point(50., 50.), 40.);
+        let points = poisson_disk(&circle, 5., &mut rng);
+
+        assert!(points.iter().all(|p| circle.contains(p)));
+        for (i, a) in points.iter().enumerate() {
+            assert!(points[i + 1..].iter().all(|b| a.distance_to(b) >= 5.));
+        }
+
+        // No gaps large enough to fit another point, away from the edge
+        let mut probe = ChaCha8Rng::seed_from_u64(2);
+        let inner = Circle::new(Point(50., 50.), 30.);
+        for p in sample_uniform(&inner, 200, &mut probe) {
+            assert!(points.iter().any(|q| p.distance_to(q) < 10.));
+        }
+    }
+
+    #[test]
+    fn poisson_disk_variable_spacing() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let rect = Rectangle::new(Point(0., 0.), 100., 50.);
+        let spacing = |p: &Point| if p.0 < 50. { 2. } else { 8. };
+        let points = poisson_disk_variable(&rect, 2.0..8.0, spacing, &mut rng);
+
+        let left = points.iter().filter(|p| p.0 < 50.).count();
+        assert!(left > (points.len() - left) * 8);
+
+        for (i, a) in points.iter().enumerate() {
+            assert!(points[i + 1..]
+                .iter()
+                .all(|b| a.distance_to(b) >= (spacing(a) + spacing(b)) / 2.));
+        }
+    }
+}
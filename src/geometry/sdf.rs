@@ -0,0 +1,168 @@
+use std::fmt::Debug;
+
+use crate::shapes::{point::Point, shape::Shape};
+
+/**
+A signed distance field: for any point it gives the distance to the nearest outline, which is
+negative inside and positive outside. Fields are built from shapes and combined into new
+fields, and can then be sampled anywhere, e.g. to fade the density of a painting out by how
+far away it is from a shape.
+
+Example
+```
+use generative_art::{
+    geometry::sdf::DistanceField,
+    shapes::{circle::Circle, point::Point, rectangle::Rectangle},
+};
+
+let field = DistanceField::shape(Circle::new(Point(0.0, 0.0), 10.0))
+    .smooth_union(
+        DistanceField::shape(Circle::new(Point(15.0, 0.0), 10.0)),
+        5.0,
+    )
+    .subtract(DistanceField::shape(Rectangle::new(Point(-2.0, -2.0), 4.0, 4.0)));
+
+assert!(field.sample(&Point(7.5, 0.0)) < 0.0);
+assert!(!field.contains(&Point(0.0, 0.0)));
+```
+*/
+pub enum DistanceField {
+    /// The signed distance to a single shape, see [`Shape::distance_to`]
+    Shape(Box<dyn Shape>),
+
+    /// Inside either field
+    Union(Box<DistanceField>, Box<DistanceField>),
+
+    /// Inside either field, with the seams between them rounded off over the given distance
+    SmoothUnion(Box<DistanceField>, Box<DistanceField>, f64),
+
+    /// Inside the first field, but not the second
+    Subtract(Box<DistanceField>, Box<DistanceField>),
+
+    /// Inside both fields
+    Intersect(Box<DistanceField>, Box<DistanceField>),
+}
+
+impl DistanceField {
+    /// A field with the signed distance to {shape}
+    pub fn shape(shape: impl Shape + 'static) -> DistanceField {
+        DistanceField::Shape(Box::new(shape))
+    }
+
+    /// Everything that is inside this field or {other}
+    pub fn union(self, other: DistanceField) -> DistanceField {
+        DistanceField::Union(Box::new(self), Box::new(other))
+    }
+
+    /// Everything that is inside this field or {other}, where the fields melt together
+    /// wherever they are closer than {smoothness} to each other.
+    pub fn smooth_union(self, other: DistanceField, smoothness: f64) -> DistanceField {
+        DistanceField::SmoothUnion(Box::new(self), Box::new(other), smoothness)
+    }
+
+    /// Everything that is inside this field, but not inside {other}
+    pub fn subtract(self, other: DistanceField) -> DistanceField {
+        DistanceField::Subtract(Box::new(self), Box::new(other))
+    }
+
+    /// Everything that is inside both this field and {other}
+    pub fn intersect(self, other: DistanceField) -> DistanceField {
+        DistanceField::Intersect(Box::new(self), Box::new(other))
+    }
+
+    /// The signed distance at {point}. Only plain shapes and unions give exact distances,
+    /// the other combinations give a lower bound outside the field, which is accurate
+    /// enough for fading and thresholds.
+    pub fn sample(&self, point: &Point) -> f64 {
+        match self {
+            DistanceField::Shape(shape) => shape.distance_to(point),
+            DistanceField::Union(a, b) => a.sample(point).min(b.sample(point)),
+            DistanceField::SmoothUnion(a, b, smoothness) => {
+                smooth_min(a.sample(point), b.sample(point), *smoothness)
+            }
+            DistanceField::Subtract(a, b) => a.sample(point).max(-b.sample(point)),
+            DistanceField::Intersect(a, b) => a.sample(point).max(b.sample(point)),
+        }
+    }
+
+    /// True if {point} is inside the field
+    pub fn contains(&self, point: &Point) -> bool {
+        self.sample(point) < 0.0
+    }
+}
+
+impl Debug for DistanceField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistanceField::Shape(shape) => write!(f, "Shape({:?})", shape.center()),
+            DistanceField::Union(a, b) => write!(f, "Union({a:?}, {b:?})"),
+            DistanceField::SmoothUnion(a, b, k) => write!(f, "SmoothUnion({a:?}, {b:?}, {k})"),
+            DistanceField::Subtract(a, b) => write!(f, "Subtract({a:?}, {b:?})"),
+            DistanceField::Intersect(a, b) => write!(f, "Intersect({a:?}, {b:?})"),
+        }
+    }
+}
+
+/// Polynomial smooth minimum of {a} and {b}, blending over a distance of {k}.
+fn smooth_min(a: f64, b: f64, k: f64) -> f64 {
+    if k <= 0.0 {
+        return a.min(b);
+    }
+
+    let h = (0.5 + 0.5 * (b - a) / k).clamp(0.0, 1.0);
+
+    b + (a - b) * h - k * h * (1.0 - h)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{
+        blob::Blob, circle::Circle, path::Path, point::Point, rectangle::Rectangle, shape::Shape,
+    };
+
+    use super::DistanceField;
+
+    #[test]
+    fn signed_distance_of_shapes() {
+        let circle = Circle::new(Point(0., 0.), 10.);
+        assert_eq!(circle.distance_to(&Point(0., 0.)), -10.);
+        assert_eq!(circle.distance_to(&Point(15., 0.)), 5.);
+
+        let rect = Rectangle::new(Point(0., 0.), 10., 10.);
+        assert_eq!(rect.distance_to(&Point(5., 2.)), -2.);
+        assert_eq!(rect.distance_to(&Point(13., 14.)), 5.);
+
+        let path = rect.to_path(Default::default());
+        assert_eq!(path.distance_to(&Point(5., 2.)), -2.);
+        assert_eq!(path.distance_to(&Point(5., 12.)), 2.);
+
+        let blob = Blob::new(Point(0., 0.), 10., None);
+        assert!(blob.distance_to(&Point(0., 0.)) < -7.);
+        assert!(blob.distance_to(&Point(20., 0.)) > 7.);
+    }
+
+    #[test]
+    fn path_treats_open_rings_as_closed() {
+        let path = Path::new(
+            vec![Point(0., 0.), Point(10., 0.), Point(10., 10.)],
+            Default::default(),
+        );
+
+        assert!((path.distance_to(&Point(0., 10.)) - 50f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn combinators() {
+        let a = || DistanceField::shape(Circle::new(Point(0., 0.), 10.));
+        let b = || DistanceField::shape(Circle::new(Point(15., 0.), 10.));
+        let between = Point(7.5, 8.);
+
+        assert_eq!(a().union(b()).sample(&Point(20., 0.)), -5.);
+        assert_eq!(a().intersect(b()).sample(&Point(0., 0.)), 5.);
+        assert_eq!(a().subtract(b()).sample(&Point(10., 0.)), 5.);
+
+        // The smooth union fills in the notch between the circles
+        assert!(!a().union(b()).contains(&between));
+        assert!(a().smooth_union(b(), 5.).contains(&between));
+    }
+}
@@ -0,0 +1,239 @@
+use crate::shapes::{path::Path, point::Point};
+
+use super::polygon::{assemble, cross, difference_of, normalize, signed_area, EPSILON};
+
+/**
+Split a closed path into triangles by ear clipping. Holes are joined up with the outline
+around them first, by cutting a narrow bridge from each hole to the outline. Curved paths are
+flattened, and open paths are treated as if they were closed.
+
+Example
+```
+use generative_art::{
+    geometry::triangulate::triangulate,
+    shapes::{point::Point, rectangle::Rectangle},
+};
+
+let square = Rectangle::new(Point(0.0, 0.0), 10.0, 10.0).to_path(Default::default());
+assert_eq!(triangulate(&square).len(), 2);
+```
+*/
+pub fn triangulate(path: &Path) -> Vec<[Point; 3]> {
+    assemble(normalize(path), path.style)
+        .iter()
+        .flat_map(|part| {
+            let mut rings = part.rings().into_iter();
+            let outline = rings.next().unwrap_or_default();
+
+            clip_ears(bridge_holes(outline, rings.collect()))
+        })
+        .collect()
+}
+
+/// Join each hole to the outline, giving a single ring that goes around the outline and
+/// visits each hole on the way. Follows "Triangulation by Ear Clipping" by David Eberly.
+fn bridge_holes(outline: Vec<Point>, mut holes: Vec<Vec<Point>>) -> Vec<Point> {
+    let rightmost = |ring: &[Point]| {
+        (0..ring.len())
+            .max_by(|a, b| ring[*a].0.total_cmp(&ring[*b].0))
+            .unwrap_or(0)
+    };
+
+    holes.retain(|hole| hole.len() > 2);
+    holes.sort_by(|a, b| ring_max_x(b).total_cmp(&ring_max_x(a)));
+
+    let mut ring = outline;
+
+    for hole in holes {
+        let m = rightmost(&hole);
+        let start = hole[m];
+
+        // Look to the right of the hole for the closest edge of the ring
+        let mut closest: Option<(f64, usize)> = None;
+        for i in 0..ring.len() {
+            let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+            if (a.1 - start.1) * (b.1 - start.1) > 0.0 || a.1 == b.1 {
+                continue;
+            }
+
+            let x = a.0 + (start.1 - a.1) / (b.1 - a.1) * (b.0 - a.0);
+            if x >= start.0 && closest.is_none_or(|(closest_x, _)| x < closest_x) {
+                let end = if a.0 > b.0 { i } else { (i + 1) % ring.len() };
+                closest = Some((x, end));
+            }
+        }
+
+        let Some((x, mut visible)) = closest else {
+            continue;
+        };
+
+        // Another corner of the ring could be in the way, if so bridge to the corner
+        // that is closest in angle to the line going right.
+        let hit = Point(x, start.1);
+        let candidate = ring[visible];
+        let mut best_angle = f64::INFINITY;
+        for i in 0..ring.len() {
+            let corner = ring[i];
+            let reflex = cross(
+                &difference_of(&corner, &ring[(i + ring.len() - 1) % ring.len()]),
+                &difference_of(&ring[(i + 1) % ring.len()], &corner),
+            ) <= 0.0;
+
+            if reflex && corner != candidate && in_triangle(&corner, &start, &hit, &candidate) {
+                let angle = ((corner.1 - start.1) / (corner.0 - start.0)).abs();
+                if angle < best_angle {
+                    best_angle = angle;
+                    visible = i;
+                }
+            }
+        }
+
+        let mut merged = ring[..=visible].to_vec();
+        merged.extend(hole[m..].iter().chain(hole[..=m].iter()));
+        merged.extend(ring[visible..].iter());
+        ring = merged;
+    }
+
+    ring
+}
+
+/// Triangulate a counter-clockwise ring by cutting off one ear after the other, where an ear
+/// is a convex corner with no other corners inside the triangle it makes with its neighbors.
+fn clip_ears(mut ring: Vec<Point>) -> Vec<[Point; 3]> {
+    let mut triangles = vec![];
+
+    while ring.len() > 3 {
+        let count = ring.len();
+        let corner = |i: usize| {
+            (
+                ring[(i + count - 1) % count],
+                ring[i],
+                ring[(i + 1) % count],
+            )
+        };
+
+        let ear = (0..count).find(|i| {
+            let (a, b, c) = corner(*i);
+            cross(&difference_of(&b, &a), &difference_of(&c, &b)) > EPSILON
+                && !ring
+                    .iter()
+                    .any(|p| *p != a && *p != b && *p != c && in_triangle(p, &a, &b, &c))
+        });
+
+        // Rounding errors can leave a ring without ears, cut off the most convex corner
+        // to make sure it keeps shrinking.
+        let ear = ear.unwrap_or_else(|| {
+            (0..count)
+                .max_by(|i, j| {
+                    let turn = |k: usize| {
+                        let (a, b, c) = corner(k);
+                        cross(&difference_of(&b, &a), &difference_of(&c, &b))
+                    };
+                    turn(*i).total_cmp(&turn(*j))
+                })
+                .unwrap_or(0)
+        });
+
+        let (a, b, c) = corner(ear);
+        if signed_area(&[a, b, c]).abs() > EPSILON {
+            triangles.push([a, b, c]);
+        }
+        ring.remove(ear);
+    }
+
+    if ring.len() == 3 && signed_area(&ring).abs() > EPSILON {
+        triangles.push([ring[0], ring[1], ring[2]]);
+    }
+
+    triangles
+}
+
+fn ring_max_x(ring: &[Point]) -> f64 {
+    ring.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// True if {p} is inside or on the edge of the triangle {a}, {b}, {c}, in either direction.
+fn in_triangle(p: &Point, a: &Point, b: &Point, c: &Point) -> bool {
+    let sides = [
+        cross(&difference_of(b, a), &difference_of(p, a)),
+        cross(&difference_of(c, b), &difference_of(p, b)),
+        cross(&difference_of(a, c), &difference_of(p, c)),
+    ];
+
+    !(sides.iter().any(|s| *s < 0.0) && sides.iter().any(|s| *s > 0.0))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        geometry::polygon::signed_area,
+        shapes::{circle::Circle, path::Path, point::Point, shape::Shape},
+    };
+
+    use super::triangulate;
+
+    fn area(triangles: &[[Point; 3]]) -> f64 {
+        triangles
+            .iter()
+            .map(|triangle| signed_area(triangle) / 2.0)
+            .sum()
+    }
+
+    #[test]
+    fn concave_polygon() {
+        let path = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(10., 0.),
+                Point(10., 10.),
+                Point(5., 3.),
+                Point(0., 10.),
+            ],
+            Default::default(),
+        );
+
+        let triangles = triangulate(&path);
+
+        assert_eq!(triangles.len(), 3);
+        assert!((area(&triangles) - path.area()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn polygon_with_holes() {
+        let path = Path::from_rings(
+            vec![
+                vec![
+                    Point(0., 0.),
+                    Point(20., 0.),
+                    Point(20., 10.),
+                    Point(0., 10.),
+                ],
+                vec![Point(2., 2.), Point(2., 8.), Point(8., 8.), Point(8., 2.)],
+                vec![
+                    Point(12., 2.),
+                    Point(12., 8.),
+                    Point(18., 8.),
+                    Point(18., 2.),
+                ],
+            ],
+            Default::default(),
+        );
+
+        let triangles = triangulate(&path);
+
+        assert!((area(&triangles) - 128.).abs() < 1e-9);
+        assert!(triangles.iter().all(|[a, b, c]| {
+            let middle = Point((a.0 + b.0 + c.0) / 3., (a.1 + b.1 + c.1) / 3.);
+            path.contains(&middle)
+        }));
+    }
+
+    #[test]
+    fn circle() {
+        let circle = Circle::new(Point(0., 0.), 10.).to_path(Default::default());
+        let triangles = triangulate(&circle);
+
+        assert_eq!(triangles.len(), circle.points.len() - 3);
+        assert!((area(&triangles) - circle.area()).abs() < 1e-6);
+    }
+}
@@ -10,6 +10,7 @@
     clippy::all
 )]
 
+pub mod geometry;
 pub mod paintings;
 pub mod palette;
 pub mod shapes;
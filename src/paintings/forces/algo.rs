@@ -31,6 +31,10 @@ pub fn forces(config: Rc<&ForcesConfig>) -> Document<'static> {
     let mut rng = ChaCha20Rng::from_entropy();
 
     let mut point_map: PointMap<'_, Circle> = PointMap::new(&bounds, 20);
+
+    // Lines stop when they overlap another line by half their width, which for the widest
+    // lines is further away than the neighboring cells reach.
+    point_map.set_search_radius(250.0 * 1.5);
     let noise = SuperSimplex::new().set_seed(config.seed);
 
     for _ in 0..config.line_count {
@@ -57,6 +61,7 @@ pub fn forces(config: Rc<&ForcesConfig>) -> Document<'static> {
                 stroke_weight: Some(r),
                 ..Default::default()
             },
+            ..Default::default()
         };
 
         while inner_bounds.contains(&Point(x, y)) && line.length() < config.max_line_length {
@@ -74,7 +79,7 @@ pub fn forces(config: Rc<&ForcesConfig>) -> Document<'static> {
             if let Ok(neighbors) = point_map.get_neighbors(&circle, None) {
                 if neighbors
                     .iter()
-                    .any(|neighbor| neighbor.distance(&circle) < r / 2.)
+                    .any(|neighbor| neighbor.distance(&circle) < -r / 2.)
                 {
                     break;
                 }
@@ -101,6 +106,7 @@ pub fn forces(config: Rc<&ForcesConfig>) -> Document<'static> {
                                 stroke_weight: Some(r),
                                 stroke: palette.get_random_color(),
                                 color: None,
+                                ..Default::default()
                             },
                         )
                     })
@@ -110,6 +116,7 @@ pub fn forces(config: Rc<&ForcesConfig>) -> Document<'static> {
                     stroke_weight: Some(r),
                     stroke: line_color,
                     color: None,
+                    ..Default::default()
                 };
                 svg.add_shape(Box::new(line));
             }
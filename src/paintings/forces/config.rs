@@ -2,20 +2,15 @@ use std::fmt::Display;
 
 use clap::Parser;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ForcesPalette {
     PeachesAndCream,
+    #[default]
     OrangeAutumn,
     SpringBreak,
     RedWhiteBlack,
 }
 
-impl Default for ForcesPalette {
-    fn default() -> Self {
-        ForcesPalette::OrangeAutumn
-    }
-}
-
 #[derive(Parser, Debug, Default)]
 #[command(author, version, about, long_about = None)]
 pub struct ForcesParams {
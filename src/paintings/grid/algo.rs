@@ -15,7 +15,7 @@ pub fn generate_grid(config: Arc<GridConfig>) -> Document<'static> {
         position: Point(0.0, 0.0),
         width: config.size,
         height: config.size * 1.4,
-        color: Some(Color::Hex("#fff")),
+        color: Some(Color::hex("#fff")),
     };
 
     let inner_bounds = bounds.scale(0.9);
@@ -64,7 +64,7 @@ pub fn generate_grid(config: Arc<GridConfig>) -> Document<'static> {
                     0.5,
                 );
 
-                circle.set_color(Color::Hex("#1115"));
+                circle.set_color(Color::hex("#1115"));
 
                 points.push(circle);
             }
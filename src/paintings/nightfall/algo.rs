@@ -22,7 +22,7 @@ pub fn generate_nightfall(config: Rc<&NightfallConfig>) -> Document<'static> {
         position: Point(0.0, 0.0),
         width: config.size,
         height: config.size * 1.4,
-        color: Some(Color::Hex("#111")),
+        color: Some(Color::hex("#111")),
     };
     let scaled_bounds = bounds.scale(0.9);
 
@@ -85,35 +85,36 @@ pub fn generate_nightfall(config: Rc<&NightfallConfig>) -> Document<'static> {
         let _ = pointmap.insert(point);
     }
 
-    let clone = pointmap.clone();
-    let points = clone.get_items();
+    let points: Vec<_> = pointmap
+        .iter()
+        .map(|(handle, point)| (handle, *point))
+        .collect();
 
-    for point in points {
+    for (handle, point) in &points {
         let max_count = map::map(
             point.1,
             scaled_bounds.position.1..bounds.height - scaled_bounds.position.1,
             70.0..5.0,
         ) as usize;
 
-        if let Ok(neighbors) = pointmap.get_neighbors(point, Some(50.)) {
-            neighbors
-                .iter()
-                .filter(|n| n.distance_to(point) > 10.)
-                .take(max_count)
-                .for_each(|n| {
-                    let path = Path::new(
-                        vec![*point, *n],
-                        PathStyle {
-                            stroke_weight: Some(0.2),
-                            stroke: Some(Color::Hex("#eee")),
-                            color: None,
-                        },
-                    );
-                    svg.add_shape(Box::new(path));
-                });
-        }
-
-        pointmap.remove(*point);
+        pointmap
+            .within_radius(point, 50.)
+            .filter(|n| n.distance_to(point) > 10.)
+            .take(max_count)
+            .for_each(|n| {
+                let path = Path::new(
+                    vec![*point, *n],
+                    PathStyle {
+                        stroke_weight: Some(0.2),
+                        stroke: Some(Color::hex("#eee")),
+                        color: None,
+                        ..Default::default()
+                    },
+                );
+                svg.add_shape(Box::new(path));
+            });
+
+        pointmap.remove(*handle);
     }
 
     svg
@@ -4,7 +4,13 @@ use rand_chacha::ChaCha20Rng;
 
 use crate::{
     palette::{color::Color, palettes::Palettes, regional_palette::RegionalPalette},
-    shapes::{blob::Blob, point::Point, pointmap::PointMap, rectangle::Rectangle, shape::Shape},
+    shapes::{
+        blob::{Blob, BlobConfig},
+        point::Point,
+        pointmap::PointMap,
+        rectangle::Rectangle,
+        shape::Shape,
+    },
     svg::document::Document,
 };
 
@@ -32,6 +38,7 @@ pub fn wildlands(config: &WildlandsConfig) -> Document<'static> {
     let mut point_map = PointMap::new(&bounds, 100);
     let noise = SuperSimplex::new().set_seed(config.seed);
 
+    let blob_config = BlobConfig::default();
     let r = config.radius;
     let step_size: f64 = r * config.step_size;
 
@@ -51,7 +58,7 @@ pub fn wildlands(config: &WildlandsConfig) -> Document<'static> {
             let n = noise.get([point.0 / config.smoothness, point.1 / config.smoothness]);
             point.0 += (config.chaos * n).sin() * step_size;
             point.1 += (config.chaos * n).cos() * step_size;
-            let blob = Blob::new(point, r, line_color);
+            let blob = Blob::generate(point, r, line_color, &blob_config, &mut rng);
 
             if let Ok(neighbors) = point_map.get_neighbors(&blob, None) {
                 if neighbors
@@ -69,7 +76,13 @@ pub fn wildlands(config: &WildlandsConfig) -> Document<'static> {
 
         if line.len() > 3 {
             for blob in line {
-                let clone = Blob::new(blob.center(), blob.radius, blob.color);
+                let clone = Blob::generate(
+                    blob.center(),
+                    blob.radius,
+                    blob.color,
+                    &blob_config,
+                    &mut rng,
+                );
                 let _ = point_map.insert(blob);
                 document.add_shape(Box::new(clone));
             }
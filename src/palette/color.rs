@@ -1,7 +1,27 @@
-use std::fmt::Display;
+use std::{error::Error, fmt::Display, str::FromStr};
 
-/// Color representation
-#[derive(Clone, Copy, Debug)]
+/**
+Color representation. Colors can be written in the code, parsed from any of the ways CSS
+writes colors, and converted to other color spaces with [`Color::to_rgb`], [`Color::to_hsl`],
+[`Color::to_hsv`], [`Color::to_oklab`] and [`Color::to_oklch`].
+
+Example:
+
+```
+use generative_art::palette::color::{Color, OkLch};
+
+let red: Color = "rgb(255, 0, 0)".parse().unwrap();
+assert_eq!(red.to_hex(), Color::hex("#ff0000").to_hex());
+
+// A darker red of the same perceived hue
+let dark = OkLch {
+    lightness: red.to_oklch().lightness * 0.6,
+    ..red.to_oklch()
+};
+let dark = Color::from(dark);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Color {
     /**
     HSLa representation of a color. Good for when you want to do small variations to a given color.
@@ -10,22 +30,123 @@ pub enum Color {
 
     ```
     use generative_art::palette::color::Color::HSLa;
-    let bright_red = HSLa((0, 50.0, 65.0, 1.0));
+    let bright_red = HSLa(0, 50.0, 65.0, 1.0);
     ```
     */
     HSLa(u16, f64, f64, f64),
 
     /**
-    Hex representation for a color.
+    Hex representation for a color, written out the same way it was written in.
+
+    Example:
+
+    ```
+    use generative_art::palette::color::Color;
+    let bright_red = Color::hex("#f00");
+    let loaded: Color = String::from("#ff000080").parse().unwrap();
+    ```
+    */
+    Hex(HexCode),
+
+    /**
+    RGBa representation of a color, with red, green and blue from 0 to 255 and alpha
+    from 0 to 1.
 
     Example:
 
     ```
-    use generative_art::palette::color::Color::Hex;
-    let bright_red = Hex("#f00");
+    use generative_art::palette::color::Color::RGBa;
+    let bright_red = RGBa(255, 0, 0, 1.0);
     ```
     */
-    Hex(&'static str),
+    RGBa(u8, u8, u8, f64),
+
+    /**
+    A color in the sRGB color space with fractional channels, which is what converting from
+    the other color spaces gives back, so the channels are not rounded on the way.
+
+    Example:
+
+    ```
+    use generative_art::palette::color::{Color, OkLch};
+
+    let color = Color::hex("#E1B31E");
+    let shifted = Color::from(OkLch {
+        hue: color.to_oklch().hue + 0.5,
+        ..color.to_oklch()
+    });
+    ```
+    */
+    Rgb(Rgb),
+}
+
+impl Color {
+    /**
+    A hex color, with or without the leading '#', in any of the forms `rgb`, `rgba`,
+    `rrggbb` or `rrggbbaa`. Meant for colors written in the code, use [`str::parse`]
+    for colors loaded at runtime.
+
+    Panics if {code} is not a hex color.
+    */
+    pub const fn hex(code: &str) -> Color {
+        match HexCode::new(code) {
+            Some(code) => Color::Hex(code),
+            None => panic!("not a hex color"),
+        }
+    }
+
+    /// This color in the sRGB color space, which all the other color spaces convert through.
+    pub fn to_rgb(&self) -> Rgb {
+        match *self {
+            Color::HSLa(hue, saturation, lightness, alpha) => Rgb::from(Hsl {
+                hue: hue as f64,
+                saturation,
+                lightness,
+                alpha,
+            }),
+            Color::Hex(code) => {
+                let [red, green, blue, alpha] = code.channels();
+                Rgb {
+                    red: red as f64,
+                    green: green as f64,
+                    blue: blue as f64,
+                    alpha: alpha as f64 / 255.0,
+                }
+            }
+            Color::RGBa(red, green, blue, alpha) => Rgb {
+                red: red as f64,
+                green: green as f64,
+                blue: blue as f64,
+                alpha,
+            },
+            Color::Rgb(color) => color,
+        }
+    }
+
+    pub fn to_hsl(&self) -> Hsl {
+        Hsl::from(self.to_rgb())
+    }
+
+    pub fn to_hsv(&self) -> Hsv {
+        Hsv::from(self.to_rgb())
+    }
+
+    pub fn to_oklab(&self) -> OkLab {
+        OkLab::from(self.to_rgb())
+    }
+
+    pub fn to_oklch(&self) -> OkLch {
+        OkLch::from(self.to_oklab())
+    }
+
+    /// This color as a hex code, which only has an alpha channel if the color is transparent.
+    /// Hex colors keep the code they were written with.
+    pub fn to_hex(&self) -> HexCode {
+        match self {
+            Color::Hex(code) => *code,
+            _ => HexCode::from(self.to_rgb()),
+        }
+    }
 }
 
 impl Display for Color {
@@ -35,6 +156,756 @@ impl Display for Color {
             Color::HSLa(h, s, l, a) => {
                 write!(f, "hsla({}deg, {:.2}%, {:.2}%, {:.2})", h, s, l, a)
             }
+            Color::RGBa(r, g, b, a) => write!(f, "rgba({}, {}, {}, {:.2})", r, g, b, a),
+            Color::Rgb(color) => {
+                let [r, g, b] =
+                    [color.red, color.green, color.blue].map(|channel| channel.clamp(0.0, 255.0));
+                write!(f, "rgba({:.2}, {:.2}, {:.2}, {:.2})", r, g, b, color.alpha)
+            }
+        }
+    }
+}
+
+/**
+Parses the ways CSS writes colors: hex codes like `#f00`, `#ff0000` or `#ff000080`, the
+`rgb()`, `rgba()`, `hsl()` and `hsla()` functions with commas or spaces between the values,
+and the named colors, like `rebeccapurple`.
+
+Hues in whole degrees give a [`Color::HSLa`], any other hue is converted to a [`Color::Rgb`]
+so it isn't rounded.
+*/
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let lowercase = text.to_ascii_lowercase();
+        let error = || ParseColorError {
+            input: s.to_string(),
+        };
+
+        if text.starts_with('#') {
+            return HexCode::new(text).map(Color::Hex).ok_or_else(error);
+        }
+
+        if let Some(values) = arguments(&lowercase, "rgb") {
+            let [red, green, blue] = [values[0], values[1], values[2]].map(|value| {
+                match value.strip_suffix('%') {
+                    Some(percentage) => percentage.parse::<f64>().map(|p| p * 2.55),
+                    None => value.parse::<f64>(),
+                }
+                .map(|channel| channel.round().clamp(0.0, 255.0) as u8)
+            });
+
+            return Ok(Color::RGBa(
+                red.map_err(|_| error())?,
+                green.map_err(|_| error())?,
+                blue.map_err(|_| error())?,
+                alpha(values.get(3)).ok_or_else(error)?,
+            ));
+        }
+
+        if let Some(values) = arguments(&lowercase, "hsl") {
+            let hue = values[0]
+                .trim_end_matches("deg")
+                .parse::<f64>()
+                .map_err(|_| error())?;
+            let [saturation, lightness] =
+                [values[1], values[2]].map(|value| value.trim_end_matches('%').parse::<f64>());
+
+            let hsl = Hsl {
+                hue: hue.rem_euclid(360.0),
+                saturation: saturation.map_err(|_| error())?.clamp(0.0, 100.0),
+                lightness: lightness.map_err(|_| error())?.clamp(0.0, 100.0),
+                alpha: alpha(values.get(3)).ok_or_else(error)?,
+            };
+
+            if hsl.hue.fract() != 0.0 {
+                return Ok(Color::from(hsl));
+            }
+
+            return Ok(Color::HSLa(
+                hsl.hue as u16,
+                hsl.saturation,
+                hsl.lightness,
+                hsl.alpha,
+            ));
         }
+
+        NAMED_COLORS
+            .iter()
+            .find(|(name, _)| *name == lowercase)
+            .and_then(|(_, code)| HexCode::new(code))
+            .map(Color::Hex)
+            .ok_or_else(error)
+    }
+}
+
+/// The 3 or 4 values in {text} if it calls the CSS function {name}, or its variant with alpha
+fn arguments<'a>(text: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let rest = text.strip_prefix(name)?;
+    let rest = rest.strip_prefix('a').unwrap_or(rest);
+    let inside = rest.trim_start().strip_prefix('(')?.strip_suffix(')')?;
+
+    let values: Vec<&str> = inside
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|value| !value.is_empty())
+        .collect();
+
+    (3..=4).contains(&values.len()).then_some(values)
+}
+
+/// An alpha value from 0 to 1, or as a percentage, fully opaque if there is none
+fn alpha(value: Option<&&str>) -> Option<f64> {
+    let Some(value) = value else {
+        return Some(1.0);
+    };
+
+    match value.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f64>().ok().map(|p| p / 100.0),
+        None => value.parse::<f64>().ok(),
+    }
+    .map(|alpha| alpha.clamp(0.0, 1.0))
+}
+
+/// The error for text that can't be parsed as a [`Color`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a color", self.input)
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// The digits of a hex color, kept the way they were written so they are written out the same
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HexCode {
+    digits: [u8; 8],
+    len: u8,
+}
+
+impl HexCode {
+    /// The digits of {code}, None if it isn't a hex color of 3, 4, 6 or 8 digits.
+    /// The leading '#' is optional.
+    pub const fn new(code: &str) -> Option<HexCode> {
+        let bytes = code.as_bytes();
+        let start = if !bytes.is_empty() && bytes[0] == b'#' {
+            1
+        } else {
+            0
+        };
+        let len = bytes.len() - start;
+        if !matches!(len, 3 | 4 | 6 | 8) {
+            return None;
+        }
+
+        let mut digits = [0; 8];
+        let mut i = 0;
+        while i < len {
+            if !bytes[start + i].is_ascii_hexdigit() {
+                return None;
+            }
+            digits[i] = bytes[start + i];
+            i += 1;
+        }
+
+        Some(HexCode {
+            digits,
+            len: len as u8,
+        })
+    }
+
+    /// Red, green, blue and alpha from 0 to 255
+    pub fn channels(&self) -> [u8; 4] {
+        let value = |digit: u8| (digit as char).to_digit(16).unwrap_or(0) as u8;
+        let digits = &self.digits[..self.len as usize];
+
+        let mut channels = [255; 4];
+        if digits.len() <= 4 {
+            for (channel, digit) in channels.iter_mut().zip(digits) {
+                *channel = value(*digit) * 17;
+            }
+        } else {
+            for (channel, pair) in channels.iter_mut().zip(digits.chunks(2)) {
+                *channel = value(pair[0]) * 16 + value(pair[1]);
+            }
+        }
+
+        channels
+    }
+}
+
+impl Display for HexCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#")?;
+        for digit in &self.digits[..self.len as usize] {
+            write!(f, "{}", *digit as char)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Rgb> for HexCode {
+    fn from(color: Rgb) -> Self {
+        let [red, green, blue, alpha] = color.channels();
+        let code = if alpha == 255 {
+            format!("{:02x}{:02x}{:02x}", red, green, blue)
+        } else {
+            format!("{:02x}{:02x}{:02x}{:02x}", red, green, blue, alpha)
+        };
+
+        HexCode::new(&code).unwrap_or(HexCode {
+            digits: [b'0'; 8],
+            len: 6,
+        })
+    }
+}
+
+/// A color in the sRGB color space, with red, green and blue from 0 to 255 and alpha from 0 to 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rgb {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub alpha: f64,
+}
+
+impl Rgb {
+    /// Red, green, blue and alpha rounded to whole numbers from 0 to 255
+    fn channels(&self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha * 255.0]
+            .map(|channel| channel.round().clamp(0.0, 255.0) as u8)
+    }
+
+    /// Red, green and blue from 0 to 1, with the largest and smallest of them
+    fn unit(&self) -> (f64, f64, f64, f64, f64) {
+        let (r, g, b) = (self.red / 255.0, self.green / 255.0, self.blue / 255.0);
+        (r, g, b, r.max(g).max(b), r.min(g).min(b))
+    }
+
+    /// The hue in degrees, shared by HSL and HSV
+    fn hue(&self) -> f64 {
+        let (r, g, b, max, min) = self.unit();
+        let range = max - min;
+
+        if range == 0.0 {
+            return 0.0;
+        }
+
+        let hue = if max == r {
+            (g - b) / range
+        } else if max == g {
+            (b - r) / range + 2.0
+        } else {
+            (r - g) / range + 4.0
+        };
+
+        (hue * 60.0).rem_euclid(360.0)
+    }
+
+    /// The color with {hue} in degrees, where the largest channel is {chroma} above the
+    /// smallest one, which is {min}. All values are from 0 to 1.
+    fn from_hue(hue: f64, chroma: f64, min: f64, alpha: f64) -> Rgb {
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let middle = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, middle, 0.0),
+            1 => (middle, chroma, 0.0),
+            2 => (0.0, chroma, middle),
+            3 => (0.0, middle, chroma),
+            4 => (middle, 0.0, chroma),
+            _ => (chroma, 0.0, middle),
+        };
+
+        Rgb {
+            red: (r + min) * 255.0,
+            green: (g + min) * 255.0,
+            blue: (b + min) * 255.0,
+            alpha,
+        }
+    }
+}
+
+impl From<Rgb> for Color {
+    /// Keeps the channels as they are, see [`Color::Rgb`]
+    fn from(color: Rgb) -> Self {
+        Color::Rgb(color)
+    }
+}
+
+/// Hue in degrees, saturation and lightness from 0 to 100, and alpha from 0 to 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+    pub alpha: f64,
+}
+
+impl From<Rgb> for Hsl {
+    fn from(color: Rgb) -> Self {
+        let (_, _, _, max, min) = color.unit();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        Hsl {
+            hue: color.hue(),
+            saturation: saturation * 100.0,
+            lightness: lightness * 100.0,
+            alpha: color.alpha,
+        }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(color: Hsl) -> Self {
+        let (saturation, lightness) = (color.saturation / 100.0, color.lightness / 100.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+        Rgb::from_hue(color.hue, chroma, lightness - chroma / 2.0, color.alpha)
+    }
+}
+
+/// Hue in degrees, saturation and value from 0 to 100, and alpha from 0 to 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsv {
+    pub hue: f64,
+    pub saturation: f64,
+    pub value: f64,
+    pub alpha: f64,
+}
+
+impl From<Rgb> for Hsv {
+    fn from(color: Rgb) -> Self {
+        let (_, _, _, max, min) = color.unit();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+        Hsv {
+            hue: color.hue(),
+            saturation: saturation * 100.0,
+            value: max * 100.0,
+            alpha: color.alpha,
+        }
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(color: Hsv) -> Self {
+        let value = color.value / 100.0;
+        let chroma = value * color.saturation / 100.0;
+
+        Rgb::from_hue(color.hue, chroma, value - chroma, color.alpha)
+    }
+}
+
+/**
+A color in the OKLab color space, where the same change in numbers looks like the same
+change in color, so it is good for mixing colors and making gradients. Lightness goes from
+0 to 1, {a} goes from green to red and {b} from blue to yellow.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OkLab {
+    pub lightness: f64,
+    pub a: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl From<Rgb> for OkLab {
+    fn from(color: Rgb) -> Self {
+        let linear = |channel: f64| {
+            let channel = channel / 255.0;
+            if channel <= 0.04045 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b) = (linear(color.red), linear(color.green), linear(color.blue));
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        OkLab {
+            lightness: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+            alpha: color.alpha,
+        }
+    }
+}
+
+impl From<OkLab> for Rgb {
+    fn from(color: OkLab) -> Self {
+        let OkLab {
+            lightness, a, b, ..
+        } = color;
+        let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+        let gamma = |channel: f64| {
+            let channel = if channel <= 0.0031308 {
+                channel * 12.92
+            } else {
+                1.055 * channel.powf(1.0 / 2.4) - 0.055
+            };
+            channel * 255.0
+        };
+
+        Rgb {
+            red: gamma(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+            green: gamma(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+            blue: gamma(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+            alpha: color.alpha,
+        }
+    }
+}
+
+/// OKLab with the color as a hue in degrees and how colorful it is as {chroma}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OkLch {
+    pub lightness: f64,
+    pub chroma: f64,
+    pub hue: f64,
+    pub alpha: f64,
+}
+
+impl From<OkLab> for OkLch {
+    fn from(color: OkLab) -> Self {
+        OkLch {
+            lightness: color.lightness,
+            chroma: color.a.hypot(color.b),
+            hue: color.b.atan2(color.a).to_degrees().rem_euclid(360.0),
+            alpha: color.alpha,
+        }
+    }
+}
+
+impl From<OkLch> for OkLab {
+    fn from(color: OkLch) -> Self {
+        let hue = color.hue.to_radians();
+
+        OkLab {
+            lightness: color.lightness,
+            a: color.chroma * hue.cos(),
+            b: color.chroma * hue.sin(),
+            alpha: color.alpha,
+        }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(color: Hsl) -> Self {
+        Color::from(Rgb::from(color))
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(color: Hsv) -> Self {
+        Color::from(Rgb::from(color))
+    }
+}
+
+impl From<OkLab> for Color {
+    fn from(color: OkLab) -> Self {
+        Color::from(Rgb::from(color))
+    }
+}
+
+impl From<OkLch> for Color {
+    fn from(color: OkLch) -> Self {
+        Color::from(OkLab::from(color))
+    }
+}
+
+/// The named colors of CSS
+const NAMED_COLORS: [(&str, &str); 149] = [
+    ("aliceblue", "#f0f8ff"),
+    ("antiquewhite", "#faebd7"),
+    ("aqua", "#00ffff"),
+    ("aquamarine", "#7fffd4"),
+    ("azure", "#f0ffff"),
+    ("beige", "#f5f5dc"),
+    ("bisque", "#ffe4c4"),
+    ("black", "#000000"),
+    ("blanchedalmond", "#ffebcd"),
+    ("blue", "#0000ff"),
+    ("blueviolet", "#8a2be2"),
+    ("brown", "#a52a2a"),
+    ("burlywood", "#deb887"),
+    ("cadetblue", "#5f9ea0"),
+    ("chartreuse", "#7fff00"),
+    ("chocolate", "#d2691e"),
+    ("coral", "#ff7f50"),
+    ("cornflowerblue", "#6495ed"),
+    ("cornsilk", "#fff8dc"),
+    ("crimson", "#dc143c"),
+    ("cyan", "#00ffff"),
+    ("darkblue", "#00008b"),
+    ("darkcyan", "#008b8b"),
+    ("darkgoldenrod", "#b8860b"),
+    ("darkgray", "#a9a9a9"),
+    ("darkgreen", "#006400"),
+    ("darkgrey", "#a9a9a9"),
+    ("darkkhaki", "#bdb76b"),
+    ("darkmagenta", "#8b008b"),
+    ("darkolivegreen", "#556b2f"),
+    ("darkorange", "#ff8c00"),
+    ("darkorchid", "#9932cc"),
+    ("darkred", "#8b0000"),
+    ("darksalmon", "#e9967a"),
+    ("darkseagreen", "#8fbc8f"),
+    ("darkslateblue", "#483d8b"),
+    ("darkslategray", "#2f4f4f"),
+    ("darkslategrey", "#2f4f4f"),
+    ("darkturquoise", "#00ced1"),
+    ("darkviolet", "#9400d3"),
+    ("deeppink", "#ff1493"),
+    ("deepskyblue", "#00bfff"),
+    ("dimgray", "#696969"),
+    ("dimgrey", "#696969"),
+    ("dodgerblue", "#1e90ff"),
+    ("firebrick", "#b22222"),
+    ("floralwhite", "#fffaf0"),
+    ("forestgreen", "#228b22"),
+    ("fuchsia", "#ff00ff"),
+    ("gainsboro", "#dcdcdc"),
+    ("ghostwhite", "#f8f8ff"),
+    ("gold", "#ffd700"),
+    ("goldenrod", "#daa520"),
+    ("gray", "#808080"),
+    ("green", "#008000"),
+    ("greenyellow", "#adff2f"),
+    ("grey", "#808080"),
+    ("honeydew", "#f0fff0"),
+    ("hotpink", "#ff69b4"),
+    ("indianred", "#cd5c5c"),
+    ("indigo", "#4b0082"),
+    ("ivory", "#fffff0"),
+    ("khaki", "#f0e68c"),
+    ("lavender", "#e6e6fa"),
+    ("lavenderblush", "#fff0f5"),
+    ("lawngreen", "#7cfc00"),
+    ("lemonchiffon", "#fffacd"),
+    ("lightblue", "#add8e6"),
+    ("lightcoral", "#f08080"),
+    ("lightcyan", "#e0ffff"),
+    ("lightgoldenrodyellow", "#fafad2"),
+    ("lightgray", "#d3d3d3"),
+    ("lightgreen", "#90ee90"),
+    ("lightgrey", "#d3d3d3"),
+    ("lightpink", "#ffb6c1"),
+    ("lightsalmon", "#ffa07a"),
+    ("lightseagreen", "#20b2aa"),
+    ("lightskyblue", "#87cefa"),
+    ("lightslategray", "#778899"),
+    ("lightslategrey", "#778899"),
+    ("lightsteelblue", "#b0c4de"),
+    ("lightyellow", "#ffffe0"),
+    ("lime", "#00ff00"),
+    ("limegreen", "#32cd32"),
+    ("linen", "#faf0e6"),
+    ("magenta", "#ff00ff"),
+    ("maroon", "#800000"),
+    ("mediumaquamarine", "#66cdaa"),
+    ("mediumblue", "#0000cd"),
+    ("mediumorchid", "#ba55d3"),
+    ("mediumpurple", "#9370db"),
+    ("mediumseagreen", "#3cb371"),
+    ("mediumslateblue", "#7b68ee"),
+    ("mediumspringgreen", "#00fa9a"),
+    ("mediumturquoise", "#48d1cc"),
+    ("mediumvioletred", "#c71585"),
+    ("midnightblue", "#191970"),
+    ("mintcream", "#f5fffa"),
+    ("mistyrose", "#ffe4e1"),
+    ("moccasin", "#ffe4b5"),
+    ("navajowhite", "#ffdead"),
+    ("navy", "#000080"),
+    ("oldlace", "#fdf5e6"),
+    ("olive", "#808000"),
+    ("olivedrab", "#6b8e23"),
+    ("orange", "#ffa500"),
+    ("orangered", "#ff4500"),
+    ("orchid", "#da70d6"),
+    ("palegoldenrod", "#eee8aa"),
+    ("palegreen", "#98fb98"),
+    ("paleturquoise", "#afeeee"),
+    ("palevioletred", "#db7093"),
+    ("papayawhip", "#ffefd5"),
+    ("peachpuff", "#ffdab9"),
+    ("peru", "#cd853f"),
+    ("pink", "#ffc0cb"),
+    ("plum", "#dda0dd"),
+    ("powderblue", "#b0e0e6"),
+    ("purple", "#800080"),
+    ("rebeccapurple", "#663399"),
+    ("red", "#ff0000"),
+    ("rosybrown", "#bc8f8f"),
+    ("royalblue", "#4169e1"),
+    ("saddlebrown", "#8b4513"),
+    ("salmon", "#fa8072"),
+    ("sandybrown", "#f4a460"),
+    ("seagreen", "#2e8b57"),
+    ("seashell", "#fff5ee"),
+    ("sienna", "#a0522d"),
+    ("silver", "#c0c0c0"),
+    ("skyblue", "#87ceeb"),
+    ("slateblue", "#6a5acd"),
+    ("slategray", "#708090"),
+    ("slategrey", "#708090"),
+    ("snow", "#fffafa"),
+    ("springgreen", "#00ff7f"),
+    ("steelblue", "#4682b4"),
+    ("tan", "#d2b48c"),
+    ("teal", "#008080"),
+    ("thistle", "#d8bfd8"),
+    ("tomato", "#ff6347"),
+    ("transparent", "#00000000"),
+    ("turquoise", "#40e0d0"),
+    ("violet", "#ee82ee"),
+    ("wheat", "#f5deb3"),
+    ("white", "#ffffff"),
+    ("whitesmoke", "#f5f5f5"),
+    ("yellow", "#ffff00"),
+    ("yellowgreen", "#9acd32"),
+];
+
+#[cfg(test)]
+mod test {
+    use super::{Color, Hsl, Hsv, OkLab, OkLch, Rgb};
+
+    #[test]
+    fn parse() {
+        let red = Color::hex("#ff0000");
+
+        for text in [
+            "#f00",
+            "#FF0000",
+            "#ff0000ff",
+            "rgb(255, 0, 0)",
+            "rgba(100%, 0%, 0%, 1)",
+            "rgb(255 0 0 / 100%)",
+            "hsl(0, 100%, 50%)",
+            "hsla(360deg 100% 50%)",
+            " Red ",
+        ] {
+            let color: Color = text.parse().unwrap();
+            assert_eq!(
+                color.to_rgb().channels(),
+                red.to_rgb().channels(),
+                "{}",
+                text
+            );
+        }
+
+        for text in ["#ff000", "#ggg", "rgb(1, 2)", "hsl(a, b, c)", "reddish", ""] {
+            assert!(text.parse::<Color>().is_err(), "{}", text);
+        }
+
+        let half: Color = "rgba(0, 0, 255, 0.5)".parse().unwrap();
+        assert_eq!(half.to_hex().to_string(), "#0000ff80");
+        assert_eq!(Color::hex("#E1B31E").to_string(), "#E1B31E");
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        for code in [
+            "#000",
+            "#fff",
+            "#E1B31E",
+            "#678983",
+            "#FB5252",
+            "#181d31",
+            "#12345678",
+        ] {
+            let color = Color::hex(code);
+            let rgb = color.to_rgb();
+            let back = |other: Rgb| Color::from(other).to_hex().channels();
+
+            assert_eq!(back(Rgb::from(Hsl::from(rgb))), color.to_hex().channels());
+            assert_eq!(back(Rgb::from(Hsv::from(rgb))), color.to_hex().channels());
+            assert_eq!(back(Rgb::from(OkLab::from(rgb))), color.to_hex().channels());
+            assert_eq!(
+                back(Rgb::from(OkLab::from(OkLch::from(OkLab::from(rgb))))),
+                color.to_hex().channels()
+            );
+        }
+    }
+
+    #[test]
+    fn lossless_round_trip() {
+        for code in ["#E1B31E", "#678983", "#12345678"] {
+            let color = Color::hex(code);
+            let rgb = color.to_rgb();
+
+            for back in [
+                Color::from(color.to_oklch()).to_rgb(),
+                Color::from(color.to_oklab()).to_rgb(),
+                Color::from(color.to_hsl()).to_rgb(),
+                Color::from(color.to_hsv()).to_rgb(),
+            ] {
+                // Only the precision of the OKLab matrices is lost, far less than rounding
+                assert!((back.red - rgb.red).abs() < 1e-3);
+                assert!((back.green - rgb.green).abs() < 1e-3);
+                assert!((back.blue - rgb.blue).abs() < 1e-3);
+                assert_eq!(back.alpha, rgb.alpha);
+            }
+        }
+
+        let hsl: Color = "hsl(210.5deg, 40%, 60%)".parse().unwrap();
+        assert!((hsl.to_hsl().hue - 210.5).abs() < 1e-9);
+        assert_eq!(
+            "hsl(210deg, 40%, 60%)".parse::<Color>(),
+            Ok(Color::HSLa(210, 40., 60., 1.))
+        );
+        assert_eq!(
+            Color::from(Rgb {
+                red: 300.,
+                green: 127.5,
+                blue: -2.,
+                alpha: 1.
+            })
+            .to_string(),
+            "rgba(255.00, 127.50, 0.00, 1.00)"
+        );
+    }
+
+    #[test]
+    fn known_values() {
+        let color = Color::hex("#ff8000");
+
+        let hsl = color.to_hsl();
+        assert_eq!(
+            (hsl.hue.round(), hsl.saturation, hsl.lightness.round()),
+            (30., 100., 50.)
+        );
+
+        let hsv = color.to_hsv();
+        assert_eq!(
+            (hsv.hue.round(), hsv.saturation, hsv.value),
+            (30., 100., 100.)
+        );
+
+        let white = Color::hex("#fff").to_oklab();
+        assert!((white.lightness - 1.).abs() < 1e-6 && white.a.abs() < 1e-6);
+
+        assert_eq!(
+            Color::HSLa(120, 100., 25., 1.).to_hex().to_string(),
+            "#008000"
+        );
     }
 }
@@ -9,13 +9,13 @@ pub struct Palettes;
 impl Palettes {
     /// Vibrant Orange, red, off-white against a dark background
     pub fn orange_autumn() -> (Color, Box<dyn Palette>) {
-        let background = Color::Hex("#181D31");
+        let background = Color::hex("#181D31");
         let colors = WeightedPalette::new([
-            (Color::Hex("#E1B31E"), 3),
-            (Color::Hex("#678983"), 1),
-            (Color::Hex("#FB5252"), 1),
-            (Color::Hex("#F0E9D2"), 2),
-            (Color::Hex("#E6DDC4"), 2),
+            (Color::hex("#E1B31E"), 3),
+            (Color::hex("#678983"), 1),
+            (Color::hex("#FB5252"), 1),
+            (Color::hex("#F0E9D2"), 2),
+            (Color::hex("#E6DDC4"), 2),
         ]);
 
         (background, Box::new(colors))
@@ -23,12 +23,12 @@ impl Palettes {
 
     /// Pastelly pinks, orange, red
     pub fn peaches_and_cream() -> (Color, Box<dyn Palette>) {
-        let background = Color::Hex("#EAA984");
+        let background = Color::hex("#EAA984");
         let colors = SimplePalette::new([
-            Color::Hex("#CBCBE5"),
-            Color::Hex("#EAD5C9"),
-            Color::Hex("#C4594A"),
-            Color::Hex("#8786BF"),
+            Color::hex("#CBCBE5"),
+            Color::hex("#EAD5C9"),
+            Color::hex("#C4594A"),
+            Color::hex("#8786BF"),
         ]);
 
         (background, Box::new(colors))
@@ -36,12 +36,12 @@ impl Palettes {
 
     /// Blue, white, yellow
     pub fn spring_break() -> (Color, Box<dyn Palette>) {
-        let background = Color::Hex("#F9F9F9");
+        let background = Color::hex("#F9F9F9");
         let colors = SimplePalette::new([
-            Color::Hex("#ABD2EB"),
-            Color::Hex("#5AA9E6"),
-            Color::Hex("#DFC232"),
-            Color::Hex("#BE2C58"),
+            Color::hex("#ABD2EB"),
+            Color::hex("#5AA9E6"),
+            Color::hex("#DFC232"),
+            Color::hex("#BE2C58"),
         ]);
 
         (background, Box::new(colors))
@@ -49,18 +49,18 @@ impl Palettes {
 
     /// black and white against red
     pub fn red_white_black() -> (Color, Box<dyn Palette>) {
-        let background = Color::Hex("#EC0000");
+        let background = Color::hex("#EC0000");
         let colors = WeightedPalette::new([
-            (Color::Hex("#ffffff"), 2),
-            (Color::Hex("#000231"), 1),
-            (Color::Hex("#002214"), 1),
+            (Color::hex("#ffffff"), 2),
+            (Color::hex("#000231"), 1),
+            (Color::hex("#002214"), 1),
         ]);
 
         (background, Box::new(colors))
     }
 
     pub fn wild() -> (Color, Box<dyn Palette>) {
-        let background = Color::Hex("#1d1a13");
+        let background = Color::hex("#1d1a13");
         let colors = WeightedPalette::new([
             (Color::HSLa(70, 96., 79., 1.), 2),
             (Color::HSLa(28, 86., 57., 1.), 1),
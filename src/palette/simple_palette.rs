@@ -16,9 +16,9 @@ impl<const N: usize> SimplePalette<N> {
      ```
      use generative_art::palette::{Palette, color::Color, simple_palette::SimplePalette};
      let palette = SimplePalette::new([
-     Color::Hex("#f00"),
-     Color::Hex("#0f0"),
-     Color::Hex("#00f")
+     Color::hex("#f00"),
+     Color::hex("#0f0"),
+     Color::hex("#00f")
      ]);
 
      if let Some(random_color) = palette.get_random_color() {
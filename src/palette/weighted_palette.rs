@@ -18,9 +18,9 @@ impl<const N: usize> WeightedPalette<N> {
      use generative_art::{palette::{Palette, color::Color, weighted_palette::WeightedPalette}};
 
      let palette = WeightedPalette::new([
-        (Color::Hex("#f00"), 1),
-        (Color::Hex("#0f0"), 5),
-        (Color::Hex("#00f"), 1)
+        (Color::hex("#f00"), 1),
+        (Color::hex("#0f0"), 5),
+        (Color::hex("#00f"), 1)
      ]);
 
      if let Some(random_color) = palette.get_random_color() {
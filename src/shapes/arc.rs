@@ -0,0 +1,229 @@
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use super::{
+    path::{Path, PathStyle},
+    point::Point,
+    rectangle::Rectangle,
+    segment::Segment,
+    shape::{scale_turn, Shape},
+};
+
+/// A part of the outline of a circle, going from one angle to another
+#[derive(Debug, Clone, Copy)]
+pub struct Arc {
+    /// Center of the circle the arc is a part of
+    pub center: Point,
+
+    /// Radius of the circle the arc is a part of
+    pub radius: f64,
+
+    /// Angle where the arc starts, in radians
+    pub start: f64,
+
+    /// Angle where the arc ends, in radians. The arc goes clockwise if this is larger
+    /// than {start}, and counter-clockwise if it is smaller.
+    pub end: f64,
+
+    /// Stroke width and stroke color of the arc
+    pub style: PathStyle,
+}
+
+impl Arc {
+    /**
+    Create a new arc around {center}, going from angle {start} to angle {end}, in radians.
+    Angles start at the positive x axis and go clockwise, since y points down in SVG.
+
+    Example
+    ```
+    use std::f64::consts::PI;
+    use generative_art::shapes::{arc::Arc, point::Point};
+
+    // The bottom half of a circle
+    let arc = Arc::new(Point(0.0, 0.0), 10.0, 0.0, PI, Default::default());
+    ```
+    */
+    pub fn new(center: Point, radius: f64, start: f64, end: f64, style: PathStyle) -> Arc {
+        Arc {
+            center,
+            radius,
+            start,
+            end,
+            style,
+        }
+    }
+
+    /// The point on the circle at the given angle, in radians
+    pub fn point_at(&self, angle: f64) -> Point {
+        Point(
+            self.center.0 + angle.cos() * self.radius,
+            self.center.1 + angle.sin() * self.radius,
+        )
+    }
+
+    /// Length of the arc
+    pub fn length(&self) -> f64 {
+        (self.end - self.start).abs().min(TAU) * self.radius
+    }
+
+    /// True if the arc passes through the given angle, in radians
+    pub fn spans(&self, angle: f64) -> bool {
+        let from = self.start.min(self.end);
+        let sweep = (self.end - self.start).abs();
+
+        sweep >= TAU || (angle - from).rem_euclid(TAU) <= sweep
+    }
+
+    /// Converts this arc to a [`Path`] made of [`Segment::Arc`] segments. Arcs that go all
+    /// the way around are split in two, since a single SVG arc can not start and end at the
+    /// same point.
+    pub fn to_path(&self) -> Path {
+        let sweep = (self.end - self.start).clamp(-TAU, TAU);
+        let parts = if sweep.abs() > TAU - 1e-9 { 2 } else { 1 };
+
+        let mut path = Path::new(vec![self.point_at(self.start)], self.style);
+        for part in 1..=parts {
+            let angle = self.start + sweep * part as f64 / parts as f64;
+            path.add_segment(
+                Segment::Arc {
+                    rx: self.radius,
+                    ry: self.radius,
+                    rotation: 0.0,
+                    large_arc: sweep.abs() / parts as f64 > PI,
+                    sweep: sweep > 0.0,
+                },
+                self.point_at(angle),
+            );
+        }
+
+        path
+    }
+}
+
+impl Shape for Arc {
+    fn as_svg(&self) -> String {
+        self.to_path().as_svg()
+    }
+
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        self.to_path().as_clipped_svg(bounds)
+    }
+
+    /// Center of the circle the arc is a part of
+    fn center(&self) -> Point {
+        self.center
+    }
+
+    fn bounding_box(&self) -> Option<Rectangle> {
+        // The arc reaches furthest out at its ends, or where it crosses one of the axes
+        let extremes = (0..4)
+            .map(|quarter| quarter as f64 * FRAC_PI_2)
+            .filter(|angle| self.spans(*angle))
+            .chain([self.start, self.end])
+            .map(|angle| self.point_at(angle))
+            .collect();
+
+        Path::new(extremes, self.style).bounding_box()
+    }
+
+    /// True if the point is on the arc, i.e, covered by the stroke of the arc.
+    fn contains(&self, point: &Point) -> bool {
+        let reach = self.style.stroke_weight.unwrap_or(0.0) / 2.0;
+        self.distance_to(point) <= reach.max(1e-9)
+    }
+
+    fn distance_to(&self, point: &Point) -> f64 {
+        let angle = (point.1 - self.center.1).atan2(point.0 - self.center.0);
+
+        if self.spans(angle) {
+            (self.center.distance_to(point) - self.radius).abs()
+        } else {
+            point
+                .distance_to(&self.point_at(self.start))
+                .min(point.distance_to(&self.point_at(self.end)))
+        }
+    }
+
+    fn area(&self) -> f64 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.length()
+    }
+
+    fn to_polygon(&self, tolerance: f64) -> Path {
+        self.to_path().flatten(tolerance)
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(Arc {
+            center: self.center.offset(x, y),
+            ..*self
+        })
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Arc {
+            center: self.center.rotate(angle, origin),
+            start: self.start + angle,
+            end: self.end + angle,
+            ..*self
+        })
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        let turn = scale_turn(factor);
+
+        Box::new(Arc {
+            center: self.center.scale(factor, origin),
+            radius: self.radius * factor.abs(),
+            start: self.start + turn,
+            end: self.end + turn,
+            ..*self
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::{PI, TAU};
+
+    use crate::shapes::{point::Point, rectangle::Rectangle, shape::Shape};
+
+    use super::Arc;
+
+    fn round(bounds: Rectangle) -> Rectangle {
+        Rectangle::new(
+            Point(bounds.position.0.round(), bounds.position.1.round()),
+            bounds.width.round(),
+            bounds.height.round(),
+        )
+    }
+
+    #[test]
+    fn bounding_box_of_half_circle() {
+        let arc = Arc::new(Point(0., 0.), 10., 0., PI, Default::default());
+
+        assert_eq!(
+            round(arc.bounding_box().unwrap()),
+            Rectangle::new(Point(-10., 0.), 20., 10.)
+        );
+    }
+
+    #[test]
+    fn contains_points_on_the_arc() {
+        let arc = Arc::new(Point(0., 0.), 10., 0., -PI / 2., Default::default());
+
+        assert!(arc.contains(&Point(10., 0.)));
+        assert!(arc.contains(&arc.point_at(-PI / 4.)));
+        assert!(!arc.contains(&arc.point_at(PI / 4.)));
+        assert!(!arc.contains(&Point(0., 0.)));
+    }
+
+    #[test]
+    fn full_circle_svg() {
+        let arc = Arc::new(Point(0., 0.), 10., 0., TAU, Default::default());
+
+        assert_eq!(arc.as_svg().matches(" A").count(), 2);
+    }
+}
@@ -2,13 +2,19 @@
 A splotch of a shape, like a circle but not symmetrical
 Not a binary large object
 */
-use std::f64::consts::PI;
+use std::{f64::consts::PI, ops::Range};
 
+use noise::{NoiseFn, Seedable, SuperSimplex};
 use rand::Rng;
 
-use crate::palette::color::Color;
+use crate::{geometry::intersection::path_path, palette::color::Color};
 
-use super::{path::Path, point::Point, rectangle::Rectangle, shape::Shape};
+use super::{
+    path::{Path, PathStyle},
+    point::Point,
+    rectangle::Rectangle,
+    shape::Shape,
+};
 
 /**
 * A Circle like shape, but slightly distorted to give a more natural look
@@ -28,6 +34,56 @@ pub struct Blob {
 
     /// Each point in the shape of the blob
     points: Vec<Point>,
+
+    /// Draw smooth curves through the points instead of straight lines
+    smooth: bool,
+}
+
+/**
+Settings for how a [`Blob`] is shaped, see [`Blob::generate`].
+
+Example
+```
+use generative_art::shapes::blob::BlobConfig;
+
+let pebble = BlobConfig {
+    vertices: 12..13,
+    irregularity: 0.05,
+    noise: 0.3,
+    smooth: true,
+    ..Default::default()
+};
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlobConfig {
+    /// Range to pick the number of points around the outline from, at least 3 are used
+    pub vertices: Range<usize>,
+
+    /// How far each point may randomly be moved in or out, as a fraction of the radius
+    pub irregularity: f64,
+
+    /// How much the outline is bent by a smooth noise field, as a fraction of the radius.
+    /// Unlike {irregularity}, neighboring points are moved by similar amounts.
+    pub noise: f64,
+
+    /// How many bumps the noise makes around the outline, roughly
+    pub noise_frequency: f64,
+
+    /// Draw the outline as smooth curves through the points instead of straight lines
+    pub smooth: bool,
+}
+
+impl Default for BlobConfig {
+    fn default() -> Self {
+        BlobConfig {
+            vertices: 7..15,
+            irregularity: 0.2,
+            noise: 0.0,
+            noise_frequency: 1.0,
+            smooth: false,
+        }
+    }
 }
 
 impl PartialEq for Blob {
@@ -41,18 +97,83 @@ impl PartialEq for Blob {
 }
 
 impl Blob {
-    /// Create a new blob at a given {position} with a given @radius
+    /// Create a new blob at a given {position} with a given @radius, using the default
+    /// [`BlobConfig`] and a random shape that can't be reproduced.
     pub fn new(position: Point, radius: f64, color: Option<Color>) -> Blob {
-        let mut rng = rand::thread_rng();
-        let count = rng.gen_range(7..15);
+        Blob::generate(
+            position,
+            radius,
+            color,
+            &BlobConfig::default(),
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /**
+    Create a new blob at a given {position} with a given {radius}, shaped by {config}. All
+    randomness comes from {rng}, so a seeded RNG always gives the same blob.
+
+    Example
+    ```
+    use generative_art::shapes::{
+        blob::{Blob, BlobConfig},
+        point::Point,
+        shape::Shape,
+    };
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let config = BlobConfig {
+        smooth: true,
+        ..Default::default()
+    };
+
+    let a = Blob::generate(Point(0.0, 0.0), 10.0, None, &config, &mut ChaCha8Rng::seed_from_u64(1));
+    let b = Blob::generate(Point(0.0, 0.0), 10.0, None, &config, &mut ChaCha8Rng::seed_from_u64(1));
+
+    assert_eq!(a.as_svg(), b.as_svg());
+    ```
+    */
+    pub fn generate<R: Rng>(
+        position: Point,
+        radius: f64,
+        color: Option<Color>,
+        config: &BlobConfig,
+        rng: &mut R,
+    ) -> Blob {
+        let count = if config.vertices.is_empty() {
+            config.vertices.start
+        } else {
+            rng.gen_range(config.vertices.clone())
+        }
+        .max(3);
+
+        let noise = SuperSimplex::new().set_seed(rng.gen());
+        let irregularity = config.irregularity.abs();
+        let mut jitter = || {
+            if irregularity > 0.0 {
+                rng.gen_range(1.0 - irregularity..1.0 + irregularity)
+            } else {
+                1.0
+            }
+        };
 
         let mut points = vec![];
 
         for i in 0..count {
             let angle = (i as f64 / count as f64) * PI * 2.0;
+
+            // Sample the noise around a circle, so the outline joins up where it starts
+            let bend = 1.0
+                + config.noise
+                    * noise.get([
+                        angle.cos() * config.noise_frequency,
+                        angle.sin() * config.noise_frequency,
+                    ]);
+
             points.push(Point(
-                position.0 + angle.cos() * radius * rng.gen_range(0.8..1.2),
-                position.1 + angle.sin() * radius * rng.gen_range(0.8..1.2),
+                position.0 + angle.cos() * radius * bend * jitter(),
+                position.1 + angle.sin() * radius * bend * jitter(),
             ));
         }
 
@@ -61,12 +182,40 @@ impl Blob {
             radius,
             points,
             color,
+            smooth: config.smooth,
+        }
+    }
+
+    /// Converts the outline of this blob to a closed [`Path`].
+    pub fn to_path(&self, style: PathStyle) -> Path {
+        let path = Path::from_rings(vec![self.points.clone()], style);
+
+        if self.smooth {
+            path.catmull_rom()
+        } else {
+            path
         }
     }
 
-    /// Calculates the distance between this blob and another given blob
+    /// Calculates the distance between the outlines of this blob and another given blob,
+    /// which is zero or negative when the blobs overlap.
     pub fn distance(&self, other: &Blob) -> f64 {
-        self.center().distance_to(&other.center()) - self.radius - other.radius
+        let (a, b) = (self.to_polygon(0.1), other.to_polygon(0.1));
+
+        // The closest points between two polygons always include a corner of one of them
+        let closest = a
+            .points
+            .iter()
+            .map(|point| b.distance_to(point))
+            .chain(b.points.iter().map(|point| a.distance_to(point)))
+            .fold(f64::INFINITY, f64::min);
+
+        // Outlines can cross without any corner being inside the other blob
+        if closest > 0.0 && !path_path(&a, &b).is_empty() {
+            return 0.0;
+        }
+
+        closest
     }
 }
 
@@ -76,6 +225,15 @@ impl Shape for Blob {
             return String::from("");
         }
 
+        if self.smooth {
+            return self
+                .to_path(PathStyle {
+                    color: self.color,
+                    ..Default::default()
+                })
+                .as_svg();
+        }
+
         let fill: String = match &self.color {
             Some(color) => format!("fill=\"{}\" ", color),
             _ => String::from(""),
@@ -93,17 +251,73 @@ impl Shape for Blob {
     }
 
     fn contains(&self, point: &Point) -> bool {
-        let path = Path::new(self.points.clone(), Default::default());
-        path.contains(point)
+        self.to_path(Default::default()).contains(point)
     }
 
     fn center(&self) -> Point {
         Point(self.position.0, self.position.1)
     }
 
+    fn to_polygon(&self, tolerance: f64) -> Path {
+        let path = self.to_path(PathStyle {
+            color: self.color,
+            ..Default::default()
+        });
+
+        if self.smooth {
+            path.flatten(tolerance)
+        } else {
+            path
+        }
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(Blob {
+            position: self.position.offset(x, y),
+            points: self.points.iter().map(|p| p.offset(x, y)).collect(),
+            ..self.clone()
+        })
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Blob {
+            position: self.position.rotate(angle, origin),
+            points: self
+                .points
+                .iter()
+                .map(|p| p.rotate(angle, origin))
+                .collect(),
+            ..self.clone()
+        })
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Blob {
+            position: self.position.scale(factor, origin),
+            radius: self.radius * factor.abs(),
+            points: self
+                .points
+                .iter()
+                .map(|p| p.scale(factor, origin))
+                .collect(),
+            ..self.clone()
+        })
+    }
+
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        let style = PathStyle {
+            color: self.color,
+            ..Default::default()
+        };
+
+        match self.to_path(style).clip_polygon(bounds) {
+            Some(path) => path.as_svg(),
+            None => String::from(""),
+        }
+    }
+
     fn bounding_box(&self) -> Option<Rectangle> {
-        let path = Path::new(self.points.clone(), Default::default());
-        path.bounding_box()
+        self.to_path(Default::default()).bounding_box()
     }
 }
 
@@ -114,6 +328,83 @@ impl Default for Blob {
             radius: 0.0,
             color: None,
             points: vec![],
+            smooth: false,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::shapes::{point::Point, shape::Shape};
+
+    use super::{Blob, BlobConfig};
+
+    #[test]
+    fn seeded_blobs_are_reproducible() {
+        let config = BlobConfig {
+            noise: 0.3,
+            ..Default::default()
+        };
+        let blob = |seed| {
+            Blob::generate(
+                Point(0., 0.),
+                10.,
+                None,
+                &config,
+                &mut ChaCha8Rng::seed_from_u64(seed),
+            )
+        };
+
+        assert_eq!(blob(1).points, blob(1).points);
+        assert_ne!(blob(1).points, blob(2).points);
+    }
+
+    #[test]
+    fn regular_blob_is_a_polygon() {
+        let config = BlobConfig {
+            vertices: 6..7,
+            irregularity: 0.0,
+            ..Default::default()
+        };
+        let blob = Blob::generate(Point(0., 0.), 10., None, &config, &mut rand::thread_rng());
+
+        assert_eq!(blob.points.len(), 6);
+        assert!(blob
+            .points
+            .iter()
+            .all(|p| (p.distance_to(&Point(0., 0.)) - 10.).abs() < 1e-9));
+    }
+
+    #[test]
+    fn smooth_outline() {
+        let config = BlobConfig {
+            smooth: true,
+            ..Default::default()
+        };
+        let blob = Blob::generate(Point(0., 0.), 10., None, &config, &mut rand::thread_rng());
+
+        assert!(blob.to_path(Default::default()).is_curved());
+        assert!(blob.as_svg().contains(" C"));
+        assert!(blob.contains(&Point(0., 0.)));
+    }
+
+    #[test]
+    fn distance_between_outlines() {
+        let config = BlobConfig {
+            vertices: 4..5,
+            irregularity: 0.0,
+            ..Default::default()
+        };
+        let mut rng = rand::thread_rng();
+        let a = Blob::generate(Point(0., 0.), 10., None, &config, &mut rng);
+        let b = Blob::generate(Point(30., 0.), 10., None, &config, &mut rng);
+        let c = Blob::generate(Point(15., 0.), 10., None, &config, &mut rng);
+
+        // Diamonds with their tips pointing at each other
+        assert!((a.distance(&b) - 10.).abs() < 1e-9);
+        assert!((a.distance(&c) + 5. / 2_f64.sqrt()).abs() < 1e-9);
+    }
+}
@@ -2,7 +2,12 @@ use std::fmt::Display;
 
 use crate::palette::color::Color;
 
-use super::{point::Point, rectangle::Rectangle, shape::Shape};
+use super::{
+    path::{Path, PathStyle},
+    point::Point,
+    rectangle::Rectangle,
+    shape::{clip_outline, Shape},
+};
 
 /// A Circle
 #[derive(Clone, Copy, Debug)]
@@ -34,23 +39,21 @@ impl Circle {
 
     /// Calculate the distance between this circle and another circle.
     /// The distance will be calculated based on the edges of the circles,
-    /// not the center.
+    /// not the center, and is negative when the circles overlap.
     ///
     /// ```
     /// use generative_art::shapes::{circle::Circle, point::Point};
     /// let circle = Circle::new(Point(0.0, 0.0), 10.0);
-    /// let other = Circle::new(Point(20.0, 0.0), 10.0);
+    /// let other = Circle::new(Point(30.0, 0.0), 10.0);
     /// let distance = circle.distance(&other); // -> 10.0
     /// ```
     pub fn distance(&self, other: &Circle) -> f64 {
-        let d_x = self.center.0 - other.center.0;
-        let d_y = self.center.1 - other.center.1;
-        (d_x.powi(2) + d_y.powi(2)).sqrt() - self.radius / 2. - other.radius / 2.
+        self.center.distance_to(&other.center) - self.radius - other.radius
     }
 
     /// True if a given intersects another circle, otherwise false.
     pub fn intersects(&self, other: &Circle) -> bool {
-        self.distance(other) < self.radius / 2. + other.radius / 2.
+        self.distance(other) < 0.0
     }
 
     /// True if a given circle intersects any other circle in the, otherwise false
@@ -63,8 +66,32 @@ impl Circle {
         self.color = Some(color);
     }
 
-    /// Scale a circle by a factor of [`scale`]. The radius remains unchanged.
-    pub fn scale(&self, scale: f64) -> Circle {
+    /// Converts this circle to a closed [`Path`], with enough points that the
+    /// outline is never more than a tenth of a unit off from the true circle.
+    pub fn to_path(&self, style: PathStyle) -> Path {
+        Path::new(self.outline(0.1), style)
+    }
+
+    /// Points around the circle, no further than {tolerance} from the true circle,
+    /// where the last point is the same as the first.
+    fn outline(&self, tolerance: f64) -> Vec<Point> {
+        let step = 2.0 * (1.0 - (tolerance / self.radius).min(1.0)).acos();
+        let segments = (std::f64::consts::TAU / step).ceil().clamp(8.0, 1024.0) as usize;
+
+        (0..=segments)
+            .map(|i| {
+                let angle = i as f64 / segments as f64 * std::f64::consts::TAU;
+                Point(
+                    self.center.0 + angle.cos() * self.radius,
+                    self.center.1 + angle.sin() * self.radius,
+                )
+            })
+            .collect()
+    }
+
+    /// A copy of this circle with its radius multiplied by {scale}. The center remains
+    /// unchanged, see [`Shape::scale`] to scale away from another point.
+    pub fn scale_radius(&self, scale: f64) -> Circle {
         Circle {
             radius: self.radius * scale,
             center: self.center,
@@ -90,11 +117,24 @@ impl Shape for Circle {
         Point(self.center.0, self.center.1)
     }
 
+    /// Circles that cross the edge of the bounds are approximated by a polygon
+    /// before being cut.
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        let outline = || {
+            self.to_path(PathStyle {
+                color: self.color,
+                ..Default::default()
+            })
+        };
+
+        clip_outline(self, outline, bounds)
+    }
+
     fn bounding_box(&self) -> Option<Rectangle> {
         Some(Rectangle {
             position: Point(self.center.0 - self.radius, self.center.1 - self.radius),
-            width: self.center.0 + self.radius,
-            height: self.center.1 + self.radius,
+            width: self.radius * 2.0,
+            height: self.radius * 2.0,
             color: None,
         })
     }
@@ -102,6 +142,50 @@ impl Shape for Circle {
     fn contains(&self, point: &Point) -> bool {
         self.center().distance_to(point) < self.radius
     }
+
+    fn distance_to(&self, point: &Point) -> f64 {
+        self.center.distance_to(point) - self.radius
+    }
+
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius.powi(2)
+    }
+
+    fn perimeter(&self) -> f64 {
+        std::f64::consts::TAU * self.radius
+    }
+
+    fn to_polygon(&self, tolerance: f64) -> Path {
+        Path::new(
+            self.outline(tolerance),
+            PathStyle {
+                color: self.color,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(Circle {
+            center: self.center.offset(x, y),
+            ..*self
+        })
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Circle {
+            center: self.center.rotate(angle, origin),
+            ..*self
+        })
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Circle {
+            center: self.center.scale(factor, origin),
+            radius: self.radius * factor.abs(),
+            ..*self
+        })
+    }
 }
 
 impl PartialEq for Circle {
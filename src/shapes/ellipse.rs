@@ -0,0 +1,250 @@
+use std::{
+    f64::consts::{PI, TAU},
+    fmt::Display,
+};
+
+use crate::palette::color::Color;
+
+use super::{
+    path::{Path, PathStyle},
+    point::Point,
+    rectangle::Rectangle,
+    shape::{clip_outline, Shape},
+};
+
+/// An Ellipse, which can be rotated around its center
+#[derive(Clone, Copy, Debug)]
+pub struct Ellipse {
+    /// Center point of the ellipse
+    pub center: Point,
+
+    /// Radius along the x axis, before rotation
+    pub rx: f64,
+
+    /// Radius along the y axis, before rotation
+    pub ry: f64,
+
+    /// Rotation around the center, in radians
+    pub rotation: f64,
+
+    /// Fill color for the ellipse
+    color: Option<Color>,
+}
+
+impl Ellipse {
+    /// Create a new Ellipse at the given [`Point`] with the given radii, rotated by {rotation} radians.
+    ///
+    /// ```
+    /// use generative_art::shapes::{ellipse::Ellipse, point::Point};
+    /// let ellipse = Ellipse::new(Point(0.0, 0.0), 20.0, 10.0, std::f64::consts::FRAC_PI_4);
+    /// ```
+    pub fn new(center: Point, rx: f64, ry: f64, rotation: f64) -> Ellipse {
+        Ellipse {
+            center,
+            rx,
+            ry,
+            rotation,
+            color: None,
+        }
+    }
+
+    /// Set the fill color of the ellipse
+    pub fn set_color(&mut self, color: Color) {
+        self.color = Some(color);
+    }
+
+    /// The point on the outline of the ellipse at the given angle, in radians,
+    /// measured before the ellipse is rotated.
+    pub fn point_at(&self, angle: f64) -> Point {
+        let (sin, cos) = self.rotation.sin_cos();
+        let x = self.rx * angle.cos();
+        let y = self.ry * angle.sin();
+
+        Point(
+            self.center.0 + x * cos - y * sin,
+            self.center.1 + x * sin + y * cos,
+        )
+    }
+
+    /// Converts this ellipse to a closed [`Path`], with enough points that the
+    /// outline is never more than a tenth of a unit off from the true ellipse.
+    pub fn to_path(&self, style: PathStyle) -> Path {
+        Path::new(self.outline(0.1), style)
+    }
+
+    /// Points around the ellipse, no further than {tolerance} from the true ellipse,
+    /// where the last point is the same as the first.
+    fn outline(&self, tolerance: f64) -> Vec<Point> {
+        let radius = self.rx.max(self.ry);
+        let step = 2.0 * (1.0 - (tolerance / radius).min(1.0)).acos();
+        let segments = (TAU / step).ceil().clamp(8.0, 1024.0) as usize;
+
+        (0..=segments)
+            .map(|i| self.point_at(i as f64 / segments as f64 * TAU))
+            .collect()
+    }
+}
+
+impl Shape for Ellipse {
+    fn as_svg(&self) -> String {
+        let fill: String = match self.color {
+            Some(color) => format!("{}", color),
+            _ => String::from("transparent"),
+        };
+
+        let transform = if self.rotation == 0.0 {
+            String::from("")
+        } else {
+            format!(
+                " transform=\"rotate({:.2} {:.2} {:.2})\"",
+                self.rotation.to_degrees(),
+                self.center.0,
+                self.center.1
+            )
+        };
+
+        format!(
+            "<ellipse cx=\"{:.2}\" cy=\"{:.2}\" rx=\"{:.2}\" ry=\"{:.2}\"{} fill=\"{}\" />",
+            self.center.0, self.center.1, self.rx, self.ry, transform, fill
+        )
+    }
+
+    fn center(&self) -> Point {
+        self.center
+    }
+
+    /// Ellipses that cross the edge of the bounds are approximated by a polygon
+    /// before being cut.
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        let outline = || {
+            self.to_path(PathStyle {
+                color: self.color,
+                ..Default::default()
+            })
+        };
+
+        clip_outline(self, outline, bounds)
+    }
+
+    fn bounding_box(&self) -> Option<Rectangle> {
+        let (sin, cos) = self.rotation.sin_cos();
+        let half_width = ((self.rx * cos).powi(2) + (self.ry * sin).powi(2)).sqrt();
+        let half_height = ((self.rx * sin).powi(2) + (self.ry * cos).powi(2)).sqrt();
+
+        Some(Rectangle::new(
+            Point(self.center.0 - half_width, self.center.1 - half_height),
+            half_width * 2.0,
+            half_height * 2.0,
+        ))
+    }
+
+    fn contains(&self, point: &Point) -> bool {
+        // Rotate the point back, so the ellipse can be treated as if it is not rotated
+        let (sin, cos) = (-self.rotation).sin_cos();
+        let d_x = point.0 - self.center.0;
+        let d_y = point.1 - self.center.1;
+        let x = d_x * cos - d_y * sin;
+        let y = d_x * sin + d_y * cos;
+
+        (x / self.rx).powi(2) + (y / self.ry).powi(2) < 1.0
+    }
+
+    fn area(&self) -> f64 {
+        PI * self.rx * self.ry
+    }
+
+    /// Ramanujan's approximation of the perimeter, which is exact for circles
+    fn perimeter(&self) -> f64 {
+        let (a, b) = (self.rx, self.ry);
+        PI * (3.0 * (a + b) - ((3.0 * a + b) * (a + 3.0 * b)).sqrt())
+    }
+
+    fn to_polygon(&self, tolerance: f64) -> Path {
+        Path::new(
+            self.outline(tolerance),
+            PathStyle {
+                color: self.color,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(Ellipse {
+            center: self.center.offset(x, y),
+            ..*self
+        })
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Ellipse {
+            center: self.center.rotate(angle, origin),
+            rotation: self.rotation + angle,
+            ..*self
+        })
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Ellipse {
+            center: self.center.scale(factor, origin),
+            rx: self.rx * factor.abs(),
+            ry: self.ry * factor.abs(),
+            ..*self
+        })
+    }
+}
+
+impl Display for Ellipse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "x:{} y:{} rx:{} ry:{} rotation:{}",
+            self.center.0, self.center.1, self.rx, self.ry, self.rotation
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::shapes::{point::Point, rectangle::Rectangle, shape::Shape};
+
+    use super::Ellipse;
+
+    #[test]
+    fn contains_rotated() {
+        let ellipse = Ellipse::new(Point(0., 0.), 20., 5., FRAC_PI_2);
+
+        assert!(ellipse.contains(&Point(0., 15.)));
+        assert!(!ellipse.contains(&Point(15., 0.)));
+    }
+
+    #[test]
+    fn bounding_box_rotated() {
+        let ellipse = Ellipse::new(Point(0., 0.), 20., 5., FRAC_PI_2);
+        let bounds = ellipse.bounding_box().unwrap();
+
+        assert_eq!(
+            Rectangle::new(
+                Point(bounds.position.0.round(), bounds.position.1.round()),
+                bounds.width.round(),
+                bounds.height.round()
+            ),
+            Rectangle::new(Point(-5., -20.), 10., 40.)
+        );
+    }
+    #[test]
+    fn clipped_svg() {
+        let ellipse = Ellipse::new(Point(50., 50.), 20., 10., 0.);
+
+        let inside = Rectangle::new(Point(0., 0.), 100., 100.);
+        assert_eq!(ellipse.as_clipped_svg(&inside), ellipse.as_svg());
+
+        let outside = Rectangle::new(Point(200., 0.), 100., 100.);
+        assert_eq!(ellipse.as_clipped_svg(&outside), "");
+
+        let half = Rectangle::new(Point(0., 0.), 50., 100.);
+        assert!(ellipse.as_clipped_svg(&half).starts_with("<path"));
+    }
+}
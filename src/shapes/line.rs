@@ -0,0 +1,151 @@
+use super::{
+    path::{Path, PathStyle},
+    point::Point,
+    rectangle::Rectangle,
+    shape::Shape,
+};
+
+/// A straight line between two points
+#[derive(Debug, Clone, Copy)]
+pub struct Line {
+    /// Where the line starts
+    pub start: Point,
+
+    /// Where the line ends
+    pub end: Point,
+
+    /// Stroke width and stroke color of the line
+    pub style: PathStyle,
+}
+
+impl Line {
+    /// Create a new line between two points
+    ///
+    /// ```
+    /// use generative_art::shapes::{line::Line, point::Point};
+    /// let line = Line::new(Point(0.0, 0.0), Point(10.0, 10.0), Default::default());
+    /// ```
+    pub fn new(start: Point, end: Point, style: PathStyle) -> Line {
+        Line { start, end, style }
+    }
+
+    /// Length of the line
+    pub fn length(&self) -> f64 {
+        self.start.distance_to(&self.end)
+    }
+
+    /// Converts this line to a [`Path`] with two points
+    pub fn to_path(&self) -> Path {
+        Path::new(vec![self.start, self.end], self.style)
+    }
+}
+
+impl Shape for Line {
+    fn as_svg(&self) -> String {
+        let stroke: String = match self.style.stroke {
+            Some(color) => format!(" stroke=\"{color}\""),
+            None => String::from(""),
+        };
+
+        let stroke_weight: String = match self.style.stroke_weight {
+            Some(weight) => format!(" stroke-width=\"{:.2}\"", weight),
+            None => String::from(""),
+        };
+
+        format!(
+            "<line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\"{stroke}{stroke_weight}/>",
+            self.start.0, self.start.1, self.end.0, self.end.1
+        )
+    }
+
+    fn center(&self) -> Point {
+        self.start.between(&self.end, 0.5)
+    }
+
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        self.to_path().as_clipped_svg(bounds)
+    }
+
+    fn bounding_box(&self) -> Option<Rectangle> {
+        self.to_path().bounding_box()
+    }
+
+    /// True if the point is on the line, i.e, covered by the stroke of the line.
+    fn contains(&self, point: &Point) -> bool {
+        let reach = self.style.stroke_weight.unwrap_or(0.0) / 2.0;
+        self.distance_to(point) <= reach.max(1e-9)
+    }
+
+    fn distance_to(&self, point: &Point) -> f64 {
+        let d_x = self.end.0 - self.start.0;
+        let d_y = self.end.1 - self.start.1;
+        let length = d_x.powi(2) + d_y.powi(2);
+
+        if length == 0.0 {
+            return point.distance_to(&self.start);
+        }
+
+        let t = (((point.0 - self.start.0) * d_x + (point.1 - self.start.1) * d_y) / length)
+            .clamp(0.0, 1.0);
+        point.distance_to(&Point(self.start.0 + t * d_x, self.start.1 + t * d_y))
+    }
+
+    fn area(&self) -> f64 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.length()
+    }
+
+    fn to_polygon(&self, _tolerance: f64) -> Path {
+        self.to_path()
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(Line {
+            start: self.start.offset(x, y),
+            end: self.end.offset(x, y),
+            ..*self
+        })
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Line {
+            start: self.start.rotate(angle, origin),
+            end: self.end.rotate(angle, origin),
+            ..*self
+        })
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Line {
+            start: self.start.scale(factor, origin),
+            end: self.end.scale(factor, origin),
+            ..*self
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{path::PathStyle, point::Point, shape::Shape};
+
+    use super::Line;
+
+    #[test]
+    fn contains_within_stroke() {
+        let line = Line::new(
+            Point(0., 0.),
+            Point(10., 0.),
+            PathStyle {
+                stroke_weight: Some(2.),
+                ..Default::default()
+            },
+        );
+
+        assert!(line.contains(&Point(5., 0.9)));
+        assert!(!line.contains(&Point(5., 1.1)));
+        assert!(!line.contains(&Point(11.5, 0.)));
+    }
+}
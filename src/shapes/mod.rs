@@ -1,9 +1,18 @@
+/// Arc
+pub mod arc;
+
 /// Blob
 pub mod blob;
 
 /// Circle
 pub mod circle;
 
+/// Ellipse
+pub mod ellipse;
+
+/// Line
+pub mod line;
+
 /// Path
 pub mod path;
 
@@ -13,8 +22,29 @@ pub mod point;
 /// Pointmap
 pub mod pointmap;
 
+/// Quadtree
+pub mod quadtree;
+
 /// Rectangle
 pub mod rectangle;
 
+/// Regular polygon
+pub mod regular_polygon;
+
+/// R-tree
+pub mod rtree;
+
+/// Segment
+pub mod segment;
+
 /// Shape
 pub mod shape;
+
+/// Thread-safe pointmap
+pub mod shared_pointmap;
+
+/// Spatial index
+pub mod spatial_index;
+
+/// Star
+pub mod star;
@@ -1,21 +1,34 @@
+use std::{borrow::Cow, cmp::Ordering, collections::BinaryHeap};
+
 use rand::{thread_rng, Rng};
 
-use crate::palette::color::Color;
+use crate::{
+    geometry::polygon::{signed_area, winding_number},
+    palette::color::Color,
+};
 
-use super::{point::Point, rectangle::Rectangle, shape::Shape};
+use super::{point::Point, rectangle::Rectangle, segment::Segment, shape::Shape};
 
 /// An SVG path
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Path {
     /// List of points that make up the path.
     pub points: Vec<Point>,
 
+    /// How to get from each point to the next, the segment at index i ends at the
+    /// point at index i + 1. Points without a segment are joined by straight lines.
+    ///
+    /// Operations that only work with straight lines, like [`Path::simplify`] or
+    /// [`Path::clip`], flatten curves with [`Path::flatten`] first, so they give back paths
+    /// without curves.
+    pub segments: Vec<Segment>,
+
     /// Stroke width, stroke color and fill color.
     pub style: PathStyle,
 }
 
 /// A style for a given [`Path`], it can specify fill, stroke color and stroke width
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct PathStyle {
     /// The width of the stroke around this path
     ///
@@ -31,12 +44,44 @@ pub struct PathStyle {
     ///
     /// https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill
     pub color: Option<Color>,
+
+    /// Which parts of the path are filled when it crosses itself or has holes
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
+    pub fill_rule: FillRule,
+}
+
+/// Decides which points are inside a [`Path`] from how many times the path winds around them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Inside if the path winds around the point in either direction, the default in SVG.
+    /// Holes have to wind in the opposite direction of the shape around them.
+    #[default]
+    NonZero,
+
+    /// Inside if the path winds around the point an odd number of times, so every
+    /// other ring is a hole no matter which direction it winds in.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// True if a point with the given winding number is inside the path
+    pub fn is_inside(&self, winding_number: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding_number != 0,
+            FillRule::EvenOdd => winding_number % 2 != 0,
+        }
+    }
 }
 
 impl Path {
     /// Create new [`Path`] with the given [`Point`]s and [`PathStyle`]
     pub fn new(points: Vec<Point>, style: PathStyle) -> Path {
-        Path { points, style }
+        Path {
+            points,
+            style,
+            segments: vec![],
+        }
     }
 
     /// Adds another [`Point`] to the end of this path. This is good if
@@ -45,47 +90,597 @@ impl Path {
         self.points.push(point);
     }
 
+    /// Adds another [`Point`] to the end of this path, reached through the given [`Segment`].
+    pub fn add_segment(&mut self, segment: Segment, point: Point) {
+        if !self.points.is_empty() {
+            self.segments.resize(self.points.len() - 1, Segment::Line);
+            self.segments.push(segment);
+        }
+        self.points.push(point);
+    }
+
+    /// The segment that ends at the point at index {i}.
+    fn segment(&self, i: usize) -> Segment {
+        self.segments.get(i - 1).copied().unwrap_or_default()
+    }
+
+    /// True if any of the segments in the path is not a straight line.
+    pub fn is_curved(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| !matches!(segment, Segment::Line | Segment::Move))
+    }
+
+    /**
+    Turn every curve in the path into straight lines, where no point on the curve is further
+    away from the lines than {tolerance}. Plotters, and most operations on paths, only deal
+    with straight lines.
+    */
+    pub fn flatten(&self, tolerance: f64) -> Path {
+        let mut path = Path::new(
+            self.points.first().copied().into_iter().collect(),
+            self.style,
+        );
+
+        for i in 1..self.points.len() {
+            match self.segment(i) {
+                Segment::Move => path.add_segment(Segment::Move, self.points[i]),
+                segment => segment
+                    .flatten(&self.points[i - 1], &self.points[i], tolerance)
+                    .into_iter()
+                    .for_each(|point| path.add_point(point)),
+            }
+        }
+
+        path
+    }
+
+    /// This path with its curves flattened, or the path itself if it has no curves.
+    fn straight(&self) -> Cow<'_, Path> {
+        if self.is_curved() {
+            Cow::Owned(self.flatten(0.1))
+        } else {
+            Cow::Borrowed(self)
+        }
+    }
+
+    /// The sub-paths of the path as lists of points, split up at each [`Segment::Move`].
+    pub(crate) fn lines(&self) -> Vec<Vec<Point>> {
+        let mut lines: Vec<Vec<Point>> = vec![];
+
+        for (i, point) in self.points.iter().enumerate() {
+            match lines.last_mut() {
+                Some(line) if self.segment(i) != Segment::Move => line.push(*point),
+                _ => lines.push(vec![*point]),
+            }
+        }
+
+        lines
+    }
+
+    /// Create a path of straight lines, where each line after the first starts a new
+    /// sub-path, the opposite of [`Path::lines`].
+    fn from_lines(lines: Vec<Vec<Point>>, style: PathStyle) -> Path {
+        let mut path = Path::new(vec![], style);
+
+        for line in lines {
+            for (i, point) in line.into_iter().enumerate() {
+                if i == 0 {
+                    path.add_segment(Segment::Move, point);
+                } else {
+                    path.add_point(point);
+                }
+            }
+        }
+
+        path
+    }
+
+    /**
+    Smooth the path into a curve that passes through every point, using Catmull-Rom
+    splines converted to cubic Bézier segments. Each sub-path is smoothed on its own, and
+    the ones that end where they start are smoothed as a closed loop.
+
+    Example
+    ```
+    use generative_art::shapes::{path::Path, point::Point};
+
+    let line = Path::new(
+        vec![Point(0.0, 0.0), Point(20.0, 10.0), Point(40.0, 0.0), Point(60.0, 10.0)],
+        Default::default(),
+    );
+
+    let smooth = line.catmull_rom();
+    assert!(smooth.is_curved());
+    ```
+    */
+    pub fn catmull_rom(&self) -> Path {
+        let mut path = Path::new(vec![], self.style);
+
+        for line in self.lines() {
+            let segments = catmull_rom(&line);
+
+            for (i, point) in line.into_iter().enumerate() {
+                match i {
+                    0 => path.add_segment(Segment::Move, point),
+                    _ => path.add_segment(segments[i - 1], point),
+                }
+            }
+        }
+
+        path
+    }
+
+    /**
+    Smooth the path by cutting each corner {iterations} times using Chaikin's algorithm.
+    The result is still made up of straight lines, but with a lot more points, curves are
+    flattened first. The first and last points of each sub-path are kept unless it is closed.
+    */
+    pub fn chaikin(&self, iterations: usize) -> Path {
+        let lines = self
+            .straight()
+            .lines()
+            .into_iter()
+            .map(|line| chaikin(line, iterations))
+            .collect();
+
+        Path::from_lines(lines, self.style)
+    }
+
     /// Take a shape and rough it up a bit but adding a bunch of points between each,
     /// already existing, point and move those injected points around a bit.
+    /// Curves are flattened first, so the path ends up with only straight lines.
     pub fn wobble(&mut self) {
         let mut rng = thread_rng();
-        let mut new_list: Vec<Point> = vec![];
         let center = self.center();
 
-        for (i, point) in self.points.clone().into_iter().enumerate() {
-            point.offset(rng.gen_range(-3.0..3.0), rng.gen_range(-3.0..3.0));
+        let lines = self
+            .straight()
+            .lines()
+            .into_iter()
+            .map(|line| {
+                let mut new_list: Vec<Point> = vec![];
 
-            new_list.push(point);
+                for (i, point) in line.iter().enumerate() {
+                    point.offset(rng.gen_range(-3.0..3.0), rng.gen_range(-3.0..3.0));
 
-            if let Some(next) = self.points.get(i + 1) {
-                for p in (1..10).step_by(2) {
-                    let mut between = point.between(next, p as f64 / 10.);
-                    between.0 += between.angle_to(&center).cos() * rng.gen_range(-5.0..5.0);
-                    between.1 += between.angle_to(&center).sin() * rng.gen_range(-5.0..5.0);
-                    new_list.push(between);
+                    new_list.push(*point);
+
+                    if let Some(next) = line.get(i + 1) {
+                        for p in (1..10).step_by(2) {
+                            let mut between = point.between(next, p as f64 / 10.);
+                            between.0 += between.angle_to(&center).cos() * rng.gen_range(-5.0..5.0);
+                            between.1 += between.angle_to(&center).sin() * rng.gen_range(-5.0..5.0);
+                            new_list.push(between);
+                        }
+                    }
                 }
-            }
-        }
 
-        self.points = new_list
+                new_list
+            })
+            .collect();
+
+        *self = Path::from_lines(lines, self.style);
     }
 
     /// The total distance between each point in this shape, i.e, the true
-    /// length of the shape.
+    /// length of the shape. Curves are followed, the gaps between sub-paths are left out.
     pub fn length(&self) -> f64 {
-        if self.points.is_empty() {
-            return 0.0;
-        }
+        let path = self.straight();
 
         let mut total = 0.0;
-        for i in 1..self.points.len() {
-            total += self.points[i - 1].distance_to(&self.points[i])
+        for i in 1..path.points.len() {
+            if path.segment(i) != Segment::Move {
+                total += path.points[i - 1].distance_to(&path.points[i])
+            }
         }
         total
     }
 
-    /// Check if two lines intersect at any point.
-    fn intersects(a: (&Point, &Point), b: (&Point, &Point)) -> bool {
+    /**
+    How many times the path winds around {point}, counter-clockwise turns counting up and
+    clockwise turns counting down, where each ring of the path is treated as closed.
+    It is 0 for points outside of the path.
+    */
+    pub fn winding_number(&self, point: &Point) -> i32 {
+        match self.bounding_box() {
+            Some(bounds) if bounds.contains(point) => {
+                winding_number(&self.straight().rings(), point)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Shortest distance from {point} to any of the lines of the path, where each ring of
+    /// the path is treated as closed. Use [`Shape::distance_to`] for a signed distance.
+    pub fn distance_to_outline(&self, point: &Point) -> f64 {
+        self.straight()
+            .rings()
+            .iter()
+            .flat_map(|ring| {
+                (0..ring.len())
+                    .map(|i| distance_to_segment(point, &ring[i], &ring[(i + 1) % ring.len()]))
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Move every point and control point of the path with {f}, where {scale} and {rotation}
+    /// describe the transform so the radii and rotation of arcs can follow along.
+    fn map(&self, f: impl Fn(&Point) -> Point, scale: f64, rotation: f64) -> Path {
+        let segments = self
+            .segments
+            .iter()
+            .map(|segment| match *segment {
+                Segment::Quadratic(c) => Segment::Quadratic(f(&c)),
+                Segment::Cubic(c1, c2) => Segment::Cubic(f(&c1), f(&c2)),
+                Segment::Arc {
+                    rx,
+                    ry,
+                    rotation: arc_rotation,
+                    large_arc,
+                    sweep,
+                } => Segment::Arc {
+                    rx: rx * scale.abs(),
+                    ry: ry * scale.abs(),
+                    rotation: arc_rotation + rotation.to_degrees(),
+                    large_arc,
+                    sweep,
+                },
+                segment => segment,
+            })
+            .collect();
+
+        Path {
+            points: self.points.iter().map(f).collect(),
+            segments,
+            style: self.style,
+        }
+    }
+
+    /**
+    Create a path from one or more closed rings of points, where each ring after the first
+    starts a new sub-path. Rings that wind in the opposite direction of the first ring
+    become holes when the path is filled.
+    */
+    pub fn from_rings(rings: Vec<Vec<Point>>, style: PathStyle) -> Path {
+        let mut path = Path::new(vec![], style);
+
+        for ring in rings.into_iter().filter(|ring| !ring.is_empty()) {
+            let first = ring[0];
+            for (i, point) in ring.into_iter().enumerate() {
+                if i == 0 {
+                    path.add_segment(Segment::Move, point);
+                } else {
+                    path.add_point(point);
+                }
+            }
+
+            if path.points.last() != Some(&first) {
+                path.add_point(first);
+            }
+        }
+
+        path
+    }
+
+    /// Split the path up into its rings, one for each sub-path, see [`Segment::Move`].
+    /// The points where a ring closes, by returning to where it started, are left out.
+    pub fn rings(&self) -> Vec<Vec<Point>> {
+        let mut rings: Vec<Vec<Point>> = vec![];
+
+        for (i, point) in self.points.iter().enumerate() {
+            match rings.last_mut() {
+                Some(ring) if i > 0 && self.segment(i) != Segment::Move => ring.push(*point),
+                _ => rings.push(vec![*point]),
+            }
+        }
+
+        for ring in rings.iter_mut() {
+            if ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+        }
+
+        rings
+    }
+
+    /**
+    Simplify the path using the Ramer–Douglas–Peucker algorithm. Every point that is
+    closer than {tolerance} to the line drawn between the points that are kept around it
+    is dropped. The first and last points of each sub-path are always kept, and curves are
+    flattened first.
+
+    Example
+    ```
+    use generative_art::shapes::{path::Path, point::Point};
+
+    let path = Path::new(
+        vec![Point(0.0, 0.0), Point(5.0, 0.1), Point(10.0, 0.0)],
+        Default::default(),
+    );
+
+    assert_eq!(path.simplify(1.0).points, vec![Point(0.0, 0.0), Point(10.0, 0.0)]);
+    ```
+    */
+    pub fn simplify(&self, tolerance: f64) -> Path {
+        let lines = self
+            .straight()
+            .lines()
+            .into_iter()
+            .map(|line| douglas_peucker(line, tolerance))
+            .collect();
+
+        Path::from_lines(lines, self.style)
+    }
+
+    /**
+    Simplify the path using Visvalingam–Whyatt. The point that forms the smallest
+    triangle with its two neighbors is removed, one at a time, until every remaining
+    triangle has an area of at least {tolerance}. The first and last points of each sub-path
+    are always kept, and curves are flattened first.
+
+    Compared to [`Path::simplify`] this tends to keep the overall shape of a curve
+    better, at the cost of sharp corners.
+    */
+    pub fn simplify_visvalingam(&self, tolerance: f64) -> Path {
+        let lines = self
+            .straight()
+            .lines()
+            .into_iter()
+            .map(|line| visvalingam(line, tolerance))
+            .collect();
+
+        Path::from_lines(lines, self.style)
+    }
+
+    /**
+    Create a new path where the points are evenly spaced out by {spacing} along
+    the length of this path. Each sub-path is resampled on its own and curves are
+    flattened first. The last point of each sub-path is always kept, so the final step
+    can be shorter than {spacing}.
+
+    Example
+    ```
+    use generative_art::shapes::{path::Path, point::Point};
+
+    let path = Path::new(vec![Point(0.0, 0.0), Point(10.0, 0.0)], Default::default());
+    let resampled = path.resample(2.5);
+
+    assert_eq!(resampled.points.len(), 5);
+    ```
+    */
+    pub fn resample(&self, spacing: f64) -> Path {
+        let lines = self
+            .straight()
+            .lines()
+            .into_iter()
+            .map(|line| {
+                let line = Path::new(line, self.style);
+                let length = line.length();
+                if line.points.len() < 2 || spacing <= 0.0 || length == 0.0 {
+                    return line.points;
+                }
+
+                let steps = (length / spacing).floor() as usize;
+                let mut points: Vec<Point> = (0..=steps)
+                    .filter_map(|step| line.point_at(step as f64 * spacing))
+                    .collect();
+
+                if let (Some(last), Some(end)) = (points.last(), line.points.last()) {
+                    if last != end {
+                        points.push(*end);
+                    }
+                }
+
+                points
+            })
+            .collect();
+
+        Path::from_lines(lines, self.style)
+    }
+
+    /// The point that is {distance} along the path, measured from the first point and
+    /// following its curves. The distance is clamped to the length of the path.
+    pub fn point_at(&self, distance: f64) -> Option<Point> {
+        let path = self.straight();
+
+        match path.segment_at(distance) {
+            Some((i, t)) => Some(path.points[i].between(&path.points[i + 1], t)),
+            None => path.points.first().copied(),
+        }
+    }
+
+    /// Direction of the path at {distance} along the path, as a vector of length 1.
+    pub fn tangent_at(&self, distance: f64) -> Option<Point> {
+        let path = self.straight();
+        let (i, _) = path.segment_at(distance)?;
+        let (a, b) = (path.points[i], path.points[i + 1]);
+        let length = a.distance_to(&b);
+
+        Some(Point((b.0 - a.0) / length, (b.1 - a.1) / length))
+    }
+
+    /// The normal of the path at {distance} along the path, i.e, the tangent rotated
+    /// a quarter turn, as a vector of length 1.
+    pub fn normal_at(&self, distance: f64) -> Option<Point> {
+        let tangent = self.tangent_at(distance)?;
+
+        Some(Point(-tangent.1, tangent.0))
+    }
+
+    /// The part of this path that lies between {start} and {end} along the path. The gaps
+    /// between sub-paths don't count towards the distance, and are kept in the slice.
+    /// Curves are flattened, so the slice is made of straight lines.
+    pub fn slice(&self, start: f64, end: f64) -> Path {
+        let mut lines = vec![];
+        let mut travelled = 0.0;
+
+        for line in self.straight().lines() {
+            let line = Path::new(line, self.style);
+            let length = line.length();
+            let (from, to) = (start - travelled, end - travelled);
+            travelled += length;
+
+            if length == 0.0 || to < 0.0 || from > length {
+                continue;
+            }
+
+            let mut points = vec![];
+            points.extend(line.point_at(from));
+
+            let mut along = 0.0;
+            for pair in line.points.windows(2) {
+                along += pair[0].distance_to(&pair[1]);
+                if along > from && along < to {
+                    points.push(pair[1]);
+                }
+            }
+
+            points.extend(line.point_at(to));
+            points.dedup();
+            lines.push(points);
+        }
+
+        Path::from_lines(lines, self.style)
+    }
+
+    /**
+    Cut this path so that only the parts inside {bounds} remain. Filled paths are treated
+    as polygons and cut along the edges of the bounds, which yields at most one path.
+    Paths without a fill are treated as lines, which can yield several paths if the line
+    leaves and re-enters the bounds. Curves are flattened first.
+    */
+    pub fn clip(&self, bounds: &Rectangle) -> Vec<Path> {
+        if self.style.color.is_some() {
+            self.clip_polygon(bounds).into_iter().collect()
+        } else {
+            self.clip_polyline(bounds)
+        }
+    }
+
+    /// Cut this path as a line, keeping every piece of the line that is inside {bounds}.
+    /// Each sub-path is cut on its own, so the pieces never bridge the gap between them.
+    pub fn clip_polyline(&self, bounds: &Rectangle) -> Vec<Path> {
+        let mut paths: Vec<Path> = vec![];
+
+        for line in self.straight().lines() {
+            let mut current: Vec<Point> = vec![];
+
+            for pair in line.windows(2) {
+                match clip_segment(&pair[0], &pair[1], bounds) {
+                    Some((a, b)) => {
+                        if current.last() != Some(&a) {
+                            if current.len() > 1 {
+                                paths.push(Path::new(current, self.style));
+                            }
+                            current = vec![a];
+                        }
+                        current.push(b);
+                    }
+                    None => {
+                        if current.len() > 1 {
+                            paths.push(Path::new(current, self.style));
+                        }
+                        current = vec![];
+                    }
+                }
+            }
+
+            if current.len() > 1 {
+                paths.push(Path::new(current, self.style));
+            }
+        }
+
+        paths
+    }
+
+    /// Cut this path as a polygon along the edges of {bounds}, using Sutherland–Hodgman.
+    /// Each ring of the path is cut separately, so holes are kept.
+    /// Returns None if nothing of the polygon is inside the bounds.
+    pub fn clip_polygon(&self, bounds: &Rectangle) -> Option<Path> {
+        let (x1, y1) = (bounds.position.0, bounds.position.1);
+        let (x2, y2) = (x1 + bounds.width, y1 + bounds.height);
+
+        // Each edge of the bounds is described by whether it is vertical, where it is,
+        // and which side of it is inside the bounds.
+        let edges = [
+            (true, x1, 1.0),
+            (true, x2, -1.0),
+            (false, y1, 1.0),
+            (false, y2, -1.0),
+        ];
+        let inside = |p: &Point, (vertical, at, side): (bool, f64, f64)| {
+            let value = if vertical { p.0 } else { p.1 };
+            (value - at) * side >= 0.0
+        };
+        let crossing = |a: &Point, b: &Point, (vertical, at, _): (bool, f64, f64)| {
+            if vertical {
+                at_x(a, b, at)
+            } else {
+                at_y(a, b, at)
+            }
+        };
+
+        let mut rings = vec![];
+
+        for mut points in self.straight().rings() {
+            for edge in edges {
+                let input = std::mem::take(&mut points);
+
+                for (i, current) in input.iter().enumerate() {
+                    let previous = &input[(i + input.len() - 1) % input.len()];
+
+                    match (inside(current, edge), inside(previous, edge)) {
+                        (true, true) => points.push(*current),
+                        (true, false) => {
+                            points.push(crossing(previous, current, edge));
+                            points.push(*current);
+                        }
+                        (false, true) => points.push(crossing(previous, current, edge)),
+                        (false, false) => {}
+                    }
+                }
+            }
+
+            if points.len() > 2 {
+                rings.push(points);
+            }
+        }
+
+        if rings.is_empty() {
+            return None;
+        }
+
+        Some(Path::from_rings(rings, self.style))
+    }
+
+    /// Find the segment, by the index of its first point, that is {distance} along the path
+    /// and how far into that segment [0..1] the distance is. Segments without length, and the
+    /// gaps between sub-paths, are skipped. Curves are treated as straight lines, so the path
+    /// should be flattened first.
+    fn segment_at(&self, distance: f64) -> Option<(usize, f64)> {
+        let mut travelled = 0.0;
+        let mut found = None;
+
+        for i in 0..self.points.len().saturating_sub(1) {
+            let length = self.points[i].distance_to(&self.points[i + 1]);
+            if length == 0.0 || self.segment(i + 1) == Segment::Move {
+                continue;
+            }
+
+            found = Some((i, ((distance - travelled) / length).clamp(0.0, 1.0)));
+            if travelled + length >= distance {
+                break;
+            }
+            travelled += length;
+        }
+
+        found
+    }
+
+    /// Check if two lines intersect at any point. To find where they intersect, see
+    /// [`intersection`](crate::geometry::intersection).
+    pub fn intersects(a: (&Point, &Point), b: (&Point, &Point)) -> bool {
         let dx0 = a.1 .0 - a.0 .0;
         let dx1 = b.1 .0 - b.0 .0;
         let dy0 = a.1 .1 - a.0 .1;
@@ -119,15 +714,23 @@ impl Shape for Path {
             None => String::from(""),
         };
 
+        let fill_rule = match self.style.fill_rule {
+            FillRule::NonZero => "",
+            FillRule::EvenOdd => "fill-rule=\"evenodd\" ",
+        };
+
         if let Some(first) = self.points.first() {
             let mut str = self.points.iter().skip(1).enumerate().fold(
                 format!(
-                    "<path {fill}{stroke}{stroke_weight}d=\"M{:.2},{:.2}",
+                    "<path {fill}{fill_rule}{stroke}{stroke_weight}d=\"M{:.2},{:.2}",
                     first.0, first.1
                 ),
                 |mut path, (i, point)| {
                     if let Some(previous) = self.points.get(i) {
-                        if previous.0 == point.0 {
+                        let segment = self.segment(i + 1);
+                        if segment != Segment::Line {
+                            path.push_str(&segment.as_svg(point));
+                        } else if previous.0 == point.0 {
                             path.push_str(&format!(" V{:.2}", point.1));
                         } else if previous.1 == point.1 {
                             path.push_str(&format!(" H{:.2}", point.0));
@@ -146,20 +749,22 @@ impl Shape for Path {
         String::from("")
     }
 
-    fn center(&self) -> Point {
-        if let Some(bounding) = self.bounding_box() {
-            bounding.center();
-        }
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        self.clip(bounds).iter().map(|path| path.as_svg()).collect()
+    }
 
-        Point(0.0, 0.0)
+    fn center(&self) -> Point {
+        self.bounding_box()
+            .map_or(Point(0.0, 0.0), |bounding| bounding.center())
     }
 
+    /// The box around the whole outline of the path, curves included.
     fn bounding_box(&self) -> Option<Rectangle> {
-        if self.points.is_empty() {
-            return None;
+        if self.is_curved() {
+            return self.flatten(0.1).bounding_box();
         }
 
-        let p = self.points.get(0)?;
+        let p = self.points.first()?;
 
         let min_x = p.0;
         let min_y = p.1;
@@ -186,84 +791,305 @@ impl Shape for Path {
     }
 
     /**
-    How this works: it starts by getting the bounding box for the polygon.
-    After which it creates four search rays from the point out in each direction
-    to the bounding box.
-
-    It then takes two pairs of points (in other words a line) and checks how many
-    times each search ray intersects with each line,
-    if the intersection count is even, then the point is inside the polygon,
-    if the intersection count is uneven, then the point is outside the polygon.
-    **Note:** this is for each search ray, so if any ray has uneven hits
-    the line is outside
-
-    Illustrated below with an exaggerated bounding box for legabillity.
-    ```
-    /*
-    -----------------------------
-    |           |                |
-    |       ____|______          |
-    |      /    |      |         |
-    |-----|-----*------/---------|
-    |     |__   |     /          |
-    |        |__|____/           |
-    |           |                |
-    -----------------------------
-    */
-    ```
+    True if {point} is inside the path, following the [`FillRule`] of its style. Open paths
+    are treated as if they were closed, like they are when filled in SVG, and extra rings
+    started with [`Segment::Move`] count as holes or islands depending on the fill rule.
     */
     fn contains(&self, point: &Point) -> bool {
-        let bounds = if let Some(bounding) = self.bounding_box() {
-            bounding
-        } else {
-            return false;
-        };
+        self.style.fill_rule.is_inside(self.winding_number(point))
+    }
+
+    /// Filled area of the path, where holes are subtracted. Open paths are treated as if
+    /// they were closed.
+    fn area(&self) -> f64 {
+        let area: f64 = self
+            .flatten(0.1)
+            .rings()
+            .iter()
+            .map(|ring| signed_area(ring))
+            .sum();
+
+        area.abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.flatten(0.1).length()
+    }
 
-        if !bounds.contains(point) {
-            return false;
+    fn to_polygon(&self, tolerance: f64) -> Path {
+        self.flatten(tolerance)
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(self.map(|point| point.offset(x, y), 1.0, 0.0))
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(self.map(|point| point.rotate(angle, origin), 1.0, angle))
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(self.map(|point| point.scale(factor, origin), factor, 0.0))
+    }
+}
+
+/// Shortest distance from {point} to the line segment between {a} and {b}.
+fn distance_to_segment(point: &Point, a: &Point, b: &Point) -> f64 {
+    let d_x = b.0 - a.0;
+    let d_y = b.1 - a.1;
+    let length = d_x.powi(2) + d_y.powi(2);
+
+    if length == 0.0 {
+        return point.distance_to(a);
+    }
+
+    let t = (((point.0 - a.0) * d_x + (point.1 - a.1) * d_y) / length).clamp(0.0, 1.0);
+    point.distance_to(&Point(a.0 + t * d_x, a.1 + t * d_y))
+}
+
+/// Cut the line segment between {a} and {b} to the part that is inside {bounds}, using Liang–Barsky.
+fn clip_segment(a: &Point, b: &Point, bounds: &Rectangle) -> Option<(Point, Point)> {
+    let d_x = b.0 - a.0;
+    let d_y = b.1 - a.1;
+    let mut t0: f64 = 0.0;
+    let mut t1: f64 = 1.0;
+
+    for (p, q) in [
+        (-d_x, a.0 - bounds.position.0),
+        (d_x, bounds.position.0 + bounds.width - a.0),
+        (-d_y, a.1 - bounds.position.1),
+        (d_y, bounds.position.1 + bounds.height - a.1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
         }
+    }
 
-        let search = [
-            (point, &Point(point.0, bounds.position.1)),
-            (point, &Point(point.0, bounds.position.1 + bounds.height)),
-            (point, &Point(bounds.position.0 + bounds.width, point.1)),
-            (point, &Point(bounds.position.0, point.1)),
-        ];
+    if t0 > t1 {
+        return None;
+    }
 
-        for ray in search {
-            let mut intersections = 0;
+    Some((
+        Point(a.0 + t0 * d_x, a.1 + t0 * d_y),
+        Point(a.0 + t1 * d_x, a.1 + t1 * d_y),
+    ))
+}
 
-            for i in 0..self.points.len() {
-                match self.points.get(i + 1) {
-                    None => break,
-                    Some(_) => {
-                        let line = (&self.points[i], &self.points[i + 1]);
-                        if Path::intersects(line, ray) {
-                            intersections += 1;
-                        }
-                    }
-                }
+/// The point where the line through {a} and {b} crosses the vertical line at {x}.
+fn at_x(a: &Point, b: &Point, x: f64) -> Point {
+    Point(x, a.1 + (b.1 - a.1) * (x - a.0) / (b.0 - a.0))
+}
+
+/// The point where the line through {a} and {b} crosses the horizontal line at {y}.
+fn at_y(a: &Point, b: &Point, y: f64) -> Point {
+    Point(a.0 + (b.0 - a.0) * (y - a.1) / (b.1 - a.1), y)
+}
+
+/// Keep the points of a line that are needed to stay within {tolerance} of it, see
+/// [`Path::simplify`].
+fn douglas_peucker(points: Vec<Point>, tolerance: f64) -> Vec<Point> {
+    if points.len() < 3 {
+        return points;
+    }
+
+    let last = points.len() - 1;
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    let mut stack = vec![(0, last)];
+    while let Some((start, end)) = stack.pop() {
+        let mut max_distance = 0.0;
+        let mut index = start;
+
+        for i in (start + 1)..end {
+            let distance = distance_to_segment(&points[i], &points[start], &points[end]);
+            if distance > max_distance {
+                max_distance = distance;
+                index = i;
             }
+        }
+
+        if max_distance > tolerance {
+            keep[index] = true;
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+
+    points
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(point, keep)| keep.then_some(point))
+        .collect()
+}
+
+/// Drop the points of a line that form the smallest triangles, see
+/// [`Path::simplify_visvalingam`]. The triangles are kept in a heap, and the points in a
+/// linked list, so only the two neighbors of a dropped point need a new triangle.
+fn visvalingam(points: Vec<Point>, tolerance: f64) -> Vec<Point> {
+    let count = points.len();
+    if count < 3 {
+        return points;
+    }
+
+    let mut previous: Vec<usize> = (0..count).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..count).map(|i| (i + 1).min(count - 1)).collect();
+    let mut removed = vec![false; count];
+    let mut areas = vec![f64::INFINITY; count];
+    let mut heap = BinaryHeap::new();
+
+    for i in 1..count - 1 {
+        areas[i] = triangle_area(&points[i - 1], &points[i], &points[i + 1]);
+        heap.push(Triangle {
+            area: areas[i],
+            index: i,
+        });
+    }
+
+    while let Some(Triangle { area, index }) = heap.pop() {
+        // Triangles of points that were dropped, or that changed since, are left in the heap
+        if removed[index] || area != areas[index] {
+            continue;
+        }
+        if area >= tolerance {
+            break;
+        }
 
-            if intersections % 2 == 0 {
-                return false;
+        removed[index] = true;
+        let (before, after) = (previous[index], next[index]);
+        next[before] = after;
+        previous[after] = before;
+
+        for i in [before, after] {
+            if i != 0 && i != count - 1 {
+                areas[i] = triangle_area(&points[previous[i]], &points[i], &points[next[i]]);
+                heap.push(Triangle {
+                    area: areas[i],
+                    index: i,
+                });
             }
         }
-        true
     }
+
+    points
+        .into_iter()
+        .zip(removed)
+        .filter_map(|(point, removed)| (!removed).then_some(point))
+        .collect()
+}
+
+/// The triangle a point forms with its neighbors in [`visvalingam`], ordered so the heap
+/// gives back the smallest triangle first, and the first point of equally small ones.
+#[derive(Debug, PartialEq)]
+struct Triangle {
+    area: f64,
+    index: usize,
+}
+
+impl Eq for Triangle {}
+
+impl Ord for Triangle {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Triangle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The cubic curves between each point of a line and the next, see [`Path::catmull_rom`].
+/// Lines of fewer than 3 points stay straight.
+fn catmull_rom(points: &[Point]) -> Vec<Segment> {
+    let count = points.len();
+    if count < 3 {
+        return vec![Segment::Line; count.saturating_sub(1)];
+    }
+
+    let closed = points.first() == points.last();
+    let get = |i: isize| -> Point {
+        if closed {
+            // Skip the duplicated point where the loop closes
+            let n = count as isize - 1;
+            points[i.rem_euclid(n) as usize]
+        } else {
+            points[i.clamp(0, count as isize - 1) as usize]
+        }
+    };
+
+    (1..count as isize)
+        .map(|i| {
+            let (p0, p1, p2, p3) = (get(i - 2), get(i - 1), get(i), get(i + 1));
+            Segment::Cubic(
+                Point(p1.0 + (p2.0 - p0.0) / 6.0, p1.1 + (p2.1 - p0.1) / 6.0),
+                Point(p2.0 - (p3.0 - p1.0) / 6.0, p2.1 - (p3.1 - p1.1) / 6.0),
+            )
+        })
+        .collect()
+}
+
+/// Cut the corners of a line {iterations} times, see [`Path::chaikin`].
+fn chaikin(mut points: Vec<Point>, iterations: usize) -> Vec<Point> {
+    let closed = points.len() > 2 && points.first() == points.last();
+
+    for _ in 0..iterations {
+        if points.len() < 3 {
+            break;
+        }
+
+        let mut smooth: Vec<Point> = vec![];
+        if !closed {
+            smooth.push(points[0]);
+        }
+
+        for pair in points.windows(2) {
+            smooth.push(pair[0].between(&pair[1], 0.25));
+            smooth.push(pair[0].between(&pair[1], 0.75));
+        }
+
+        if closed {
+            smooth.push(smooth[0]);
+        } else {
+            smooth.push(points[points.len() - 1]);
+        }
+
+        points = smooth;
+    }
+
+    points
+}
+
+/// Area of the triangle formed by three points.
+fn triangle_area(a: &Point, b: &Point, c: &Point) -> f64 {
+    ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)).abs() / 2.0
 }
 
 #[cfg(test)]
 mod test {
-    use crate::shapes::{point::Point, rectangle::Rectangle, shape::Shape};
+    use crate::{
+        palette::color::Color,
+        shapes::{point::Point, rectangle::Rectangle, segment::Segment, shape::Shape},
+    };
 
-    use super::Path;
+    use super::{FillRule, Path};
 
     #[test]
     fn get_bounding_box() {
         let path = Path {
             points: vec![Point(0., 0.), Point(5., 5.), Point(-5., 10.)],
-            style: Default::default(),
+            ..Default::default()
         };
 
         if let Some(bounding) = path.bounding_box() {
@@ -279,6 +1105,17 @@ mod test {
         }
     }
 
+    #[test]
+    fn center_of_bounding_box() {
+        let path = Path::new(
+            vec![Point(100., 200.), Point(140., 210.), Point(120., 260.)],
+            Default::default(),
+        );
+
+        assert_eq!(path.center(), Point(120., 230.));
+        assert_eq!(Path::default().center(), Point(0., 0.));
+    }
+
     #[test]
     fn does_intersect() {
         let line1 = (&Point(0., 0.), &Point(0., 50.));
@@ -303,4 +1140,372 @@ mod test {
         assert!(path.contains(&Point(50., 50.)));
         assert!(!path.contains(&Point(500., 50.)));
     }
+
+    #[test]
+    fn point_inside_concave_open_polygon() {
+        // A U shape that is not closed, the ray from (5, 5) passes through the corner at (10, 5)
+        let path = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(4., 0.),
+                Point(4., 5.),
+                Point(6., 5.),
+                Point(6., 0.),
+                Point(10., 0.),
+                Point(10., 5.),
+                Point(10., 10.),
+                Point(0., 10.),
+            ],
+            Default::default(),
+        );
+
+        assert!(path.contains(&Point(2., 2.)));
+        assert!(path.contains(&Point(5., 7.)));
+        assert!(path.contains(&Point(8., 5.)));
+        assert!(!path.contains(&Point(5., 2.)));
+        assert!(!path.contains(&Point(11., 5.)));
+    }
+
+    #[test]
+    fn fill_rules() {
+        let outer = vec![
+            Point(0., 0.),
+            Point(10., 0.),
+            Point(10., 10.),
+            Point(0., 10.),
+        ];
+        let inner = vec![Point(3., 3.), Point(7., 3.), Point(7., 7.), Point(3., 7.)];
+
+        // Both rings wind the same way, so the inner ring is only a hole with even-odd
+        let mut path = Path::from_rings(vec![outer.clone(), inner.clone()], Default::default());
+        assert_eq!(path.winding_number(&Point(5., 5.)), 2);
+        assert!(path.contains(&Point(5., 5.)));
+
+        path.style.fill_rule = FillRule::EvenOdd;
+        assert!(!path.contains(&Point(5., 5.)));
+        assert!(path.contains(&Point(1., 5.)));
+        assert!(path.as_svg().contains("fill-rule=\"evenodd\""));
+
+        // A hole winding the other way is a hole with both rules
+        let reversed = inner.into_iter().rev().collect();
+        let path = Path::from_rings(vec![outer, reversed], Default::default());
+        assert!(!path.contains(&Point(5., 5.)));
+    }
+
+    #[test]
+    fn clip_line() {
+        let path = Path::new(
+            vec![
+                Point(-10., 5.),
+                Point(5., 5.),
+                Point(5., 20.),
+                Point(8., 5.),
+            ],
+            Default::default(),
+        );
+
+        let clipped = path.clip(&Rectangle::new(Point(0., 0.), 10., 10.));
+
+        assert_eq!(clipped.len(), 2);
+        assert_eq!(
+            clipped[0].points,
+            vec![Point(0., 5.), Point(5., 5.), Point(5., 10.)]
+        );
+        assert_eq!(clipped[1].points, vec![Point(7., 10.), Point(8., 5.)]);
+    }
+
+    #[test]
+    fn clip_filled_polygon() {
+        let mut path = Rectangle::new(Point(5., 5.), 10., 10.).to_path(Default::default());
+        path.style.color = Some(Color::hex("#000"));
+
+        let clipped = path.clip(&Rectangle::new(Point(0., 0.), 10., 10.));
+
+        assert_eq!(clipped.len(), 1);
+        assert_eq!(
+            clipped[0].bounding_box(),
+            Some(Rectangle::new(Point(5., 5.), 5., 5.))
+        );
+    }
+
+    #[test]
+    fn curved_svg() {
+        let mut path = Path::new(vec![Point(0., 0.)], Default::default());
+        path.add_segment(
+            Segment::Cubic(Point(0., 10.), Point(10., 10.)),
+            Point(10., 0.),
+        );
+        path.add_point(Point(20., 0.));
+
+        assert_eq!(
+            path.as_svg(),
+            "<path fill=\"none\" d=\"M0.00,0.00 C0.00,10.00 10.00,10.00 10.00,0.00 H20.00\"/>\n"
+        );
+    }
+
+    #[test]
+    fn smoothing_passes_through_points() {
+        let path = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(10., 10.),
+                Point(20., 0.),
+                Point(30., 10.),
+            ],
+            Default::default(),
+        );
+
+        let flat = path.catmull_rom().flatten(0.1);
+        for point in &path.points {
+            assert!(flat.points.contains(point));
+        }
+
+        let chaikin = path.chaikin(2);
+        assert_eq!(chaikin.points.len(), 16);
+        assert_eq!(chaikin.points.first(), Some(&Point(0., 0.)));
+        assert_eq!(chaikin.points.last(), Some(&Point(30., 10.)));
+    }
+
+    #[test]
+    fn smoothing_keeps_rings() {
+        let outer = vec![
+            Point(0., 0.),
+            Point(10., 0.),
+            Point(10., 10.),
+            Point(0., 10.),
+        ];
+        let inner = vec![Point(3., 3.), Point(3., 7.), Point(7., 7.), Point(7., 3.)];
+        let path = Path::from_rings(vec![outer, inner], Default::default());
+
+        let smooth = path.catmull_rom();
+        assert_eq!(smooth.points, path.points);
+        assert_eq!(smooth.segments[4], Segment::Move);
+        assert_eq!(
+            smooth
+                .segments
+                .iter()
+                .filter(|s| matches!(s, Segment::Cubic(..)))
+                .count(),
+            8
+        );
+
+        // The hole stays a hole, nothing is drawn across the gap
+        assert!(!smooth.contains(&Point(5., 5.)));
+        assert!(smooth.contains(&Point(1.5, 5.)));
+    }
+
+    #[test]
+    fn simplify_keeps_corners() {
+        let path = Path::new(
+            vec![
+                Point(0., 0.),
+                Point(5., 0.2),
+                Point(10., 0.),
+                Point(10., 5.),
+                Point(10.1, 10.),
+            ],
+            Default::default(),
+        );
+
+        assert_eq!(
+            path.simplify(1.0).points,
+            vec![Point(0., 0.), Point(10., 0.), Point(10.1, 10.)]
+        );
+        assert_eq!(
+            path.simplify_visvalingam(1.5).points,
+            vec![Point(0., 0.), Point(10., 0.), Point(10.1, 10.)]
+        );
+    }
+
+    #[test]
+    fn visvalingam_on_long_lines() {
+        let points: Vec<Point> = (0..2000)
+            .map(|i| {
+                let x = i as f64;
+                Point(x, (x / 7.).sin() * 20. + (x * 1.3).cos() * 3.)
+            })
+            .collect();
+
+        // Dropping the smallest triangle one at a time, the slow way
+        let mut expected = points.clone();
+        loop {
+            let smallest = (1..expected.len() - 1)
+                .map(|i| {
+                    let triangle = [expected[i - 1], expected[i], expected[i + 1]];
+                    (
+                        i,
+                        super::triangle_area(&triangle[0], &triangle[1], &triangle[2]),
+                    )
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+
+            match smallest {
+                Some((i, area)) if area < 10. => {
+                    expected.remove(i);
+                }
+                _ => break,
+            }
+        }
+
+        let simplified = Path::new(points, Default::default()).simplify_visvalingam(10.);
+        assert!(simplified.points.len() < 1000);
+        assert_eq!(simplified.points, expected);
+    }
+
+    #[test]
+    fn resample_evenly() {
+        let path = Path::new(
+            vec![Point(0., 0.), Point(10., 0.), Point(10., 10.)],
+            Default::default(),
+        );
+
+        let resampled = path.resample(4.);
+        assert_eq!(
+            resampled.points,
+            vec![
+                Point(0., 0.),
+                Point(4., 0.),
+                Point(8., 0.),
+                Point(10., 2.),
+                Point(10., 6.),
+                Point(10., 10.)
+            ]
+        );
+    }
+
+    #[test]
+    fn tangent_and_normal() {
+        let path = Path::new(
+            vec![Point(0., 0.), Point(10., 0.), Point(10., 10.)],
+            Default::default(),
+        );
+
+        assert_eq!(path.point_at(15.), Some(Point(10., 5.)));
+        assert_eq!(path.point_at(100.), Some(Point(10., 10.)));
+        assert_eq!(path.tangent_at(5.), Some(Point(1., 0.)));
+        assert_eq!(path.tangent_at(15.), Some(Point(0., 1.)));
+        assert_eq!(path.normal_at(5.), Some(Point(-0., 1.)));
+    }
+
+    #[test]
+    fn gaps_between_rings() {
+        let mut path = Path::new(vec![Point(0., 0.), Point(10., 0.)], Default::default());
+        path.add_segment(Segment::Move, Point(0., 10.));
+        path.add_point(Point(10., 10.));
+
+        assert_eq!(path.length(), 20.);
+        assert_eq!(path.point_at(15.), Some(Point(5., 10.)));
+        assert_eq!(path.tangent_at(10.5), Some(Point(1., 0.)));
+
+        let slice = path.slice(5., 15.);
+        assert_eq!(
+            slice.points,
+            vec![
+                Point(5., 0.),
+                Point(10., 0.),
+                Point(0., 10.),
+                Point(5., 10.)
+            ]
+        );
+        assert_eq!(slice.segments[1], Segment::Move);
+
+        let resampled = path.resample(5.);
+        assert_eq!(resampled.points.len(), 6);
+        assert_eq!(resampled.rings().len(), 2);
+
+        let clipped = path.clip(&Rectangle::new(Point(-5., -5.), 20., 20.));
+        assert_eq!(clipped.len(), 2);
+        assert_eq!(clipped[0].points, vec![Point(0., 0.), Point(10., 0.)]);
+        assert_eq!(clipped[1].points, vec![Point(0., 10.), Point(10., 10.)]);
+    }
+
+    #[test]
+    fn distances_follow_curves() {
+        let mut path = Path::new(vec![Point(0., 0.)], Default::default());
+        path.add_segment(
+            Segment::Cubic(Point(0., 100.), Point(100., 100.)),
+            Point(100., 0.),
+        );
+
+        let length = path.length();
+        assert!((length - path.flatten(0.01).length()).abs() < 0.5);
+        assert!(length > 190.);
+
+        // Halfway along the curve is at the bottom of the bulge
+        let middle = path.point_at(length / 2.).unwrap();
+        assert!(middle.distance_to(&Point(50., 75.)) < 0.5);
+        assert!(path.tangent_at(length / 2.).unwrap().0 > 0.99);
+
+        let slice = path.slice(10., 20.);
+        assert!(slice.points.iter().all(|p| p.1 > 5.));
+    }
+
+    #[test]
+    fn area_with_hole() {
+        let path = Path::from_rings(
+            vec![
+                vec![
+                    Point(0., 0.),
+                    Point(10., 0.),
+                    Point(10., 10.),
+                    Point(0., 10.),
+                ],
+                vec![Point(2., 2.), Point(2., 8.), Point(8., 8.), Point(8., 2.)],
+            ],
+            Default::default(),
+        );
+
+        assert_eq!(path.area(), 64.);
+        assert_eq!(path.perimeter(), 64.);
+    }
+
+    #[test]
+    fn curves_are_flattened() {
+        // The curve bulges 50 down between its ends
+        let mut path = Path::new(vec![Point(0., 0.)], Default::default());
+        path.add_segment(Segment::Quadratic(Point(50., 100.)), Point(100., 0.));
+        path.add_point(Point(200., 0.));
+
+        let bounds = path.bounding_box().unwrap();
+        assert!((bounds.height - 50.).abs() < 0.1);
+        assert!(path.contains(&Point(50., 30.)));
+
+        let mut wobbled = path.clone();
+        wobbled.wobble();
+        let clipped = path.clip(&Rectangle::new(Point(-10., -10.), 300., 300.));
+        assert_eq!(clipped.len(), 1);
+
+        for result in [
+            path.simplify(1.),
+            path.simplify_visvalingam(1.),
+            path.chaikin(2),
+            path.resample(5.),
+            wobbled,
+            clipped[0].clone(),
+        ] {
+            assert!(!result.is_curved());
+            assert!(result.segments.iter().all(|s| *s == Segment::Line));
+
+            let height = result.bounding_box().unwrap().height;
+            assert!(height > 40. && height < 60., "{height}");
+        }
+    }
+
+    #[test]
+    fn rotate_moves_control_points() {
+        let mut path = Path::new(vec![Point(0., 0.)], Default::default());
+        path.add_segment(Segment::Quadratic(Point(5., 5.)), Point(10., 0.));
+
+        // Flipped upside down, the curve now bulges upwards
+        let bounds = path
+            .rotate(std::f64::consts::PI, &Point(0., 0.))
+            .translate(10., 0.)
+            .to_polygon(0.01)
+            .bounding_box()
+            .unwrap();
+
+        assert!((bounds.position.0 - 0.).abs() < 1e-9);
+        assert!((bounds.position.1 + 2.5).abs() < 0.01);
+        assert!((bounds.width - 10.).abs() < 1e-9);
+    }
 }
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use super::{rectangle::Rectangle, shape::Shape};
+use super::{path::Path, rectangle::Rectangle, shape::Shape};
 
 /**
 A single point in the canvas.
@@ -46,6 +46,27 @@ impl Point {
         self.1 += y;
     }
 
+    /// Rotate this point {angle} radians around {origin}. Positive angles turn clockwise,
+    /// since the y axis points down in SVG.
+    pub fn rotate(&self, angle: f64, origin: &Point) -> Point {
+        let (sin, cos) = angle.sin_cos();
+        let d_x = self.0 - origin.0;
+        let d_y = self.1 - origin.1;
+
+        Point(
+            origin.0 + d_x * cos - d_y * sin,
+            origin.1 + d_x * sin + d_y * cos,
+        )
+    }
+
+    /// Move this point {factor} times as far away from {origin}.
+    pub fn scale(&self, factor: f64, origin: &Point) -> Point {
+        Point(
+            origin.0 + (self.0 - origin.0) * factor,
+            origin.1 + (self.1 - origin.1) * factor,
+        )
+    }
+
     /// The angle between two given points.
     pub fn angle_to(&self, other: &Point) -> f64 {
         (other.1 - self.1).atan2(other.1 - self.0)
@@ -55,7 +76,7 @@ impl Point {
     /// where 0 is at the first point and 1 is at the last point and 0.5 is right inbetween.
     pub fn between(&self, other: &Point, percent: f64) -> Point {
         Point(
-            self.0 + (other.1 - self.0) * percent,
+            self.0 + (other.0 - self.0) * percent,
             self.1 + (other.1 - self.1) * percent,
         )
     }
@@ -88,6 +109,26 @@ impl Shape for Point {
     fn contains(&self, point: &Point) -> bool {
         self.eq(point)
     }
+
+    fn distance_to(&self, point: &Point) -> f64 {
+        Point::distance_to(self, point)
+    }
+
+    fn to_polygon(&self, _tolerance: f64) -> Path {
+        Path::new(vec![*self], Default::default())
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(self.offset(x, y))
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Point::rotate(self, angle, origin))
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Point::scale(self, factor, origin))
+    }
 }
 
 #[cfg(test)]
@@ -123,4 +164,18 @@ mod tests {
         let b = a.offset(10.0, 0.0);
         assert_eq!(b, Point(15.0, -100.0));
     }
+
+    #[test]
+    fn rotate() {
+        let point = Point(10.0, 0.0).rotate(std::f64::consts::FRAC_PI_2, &Point(0.0, 0.0));
+        assert_eq!((point.0.round(), point.1.round()), (0.0, 10.0));
+    }
+
+    #[test]
+    fn between() {
+        let a = Point(0.0, 0.0);
+        let b = Point(10.0, 20.0);
+
+        assert_eq!(a.between(&b, 0.5), Point(5.0, 10.0));
+    }
 }
@@ -1,23 +1,45 @@
 use std::vec;
 
-use super::{point::Point, rectangle::Rectangle, shape::Shape};
+use super::{
+    point::Point,
+    rectangle::Rectangle,
+    shape::Shape,
+    spatial_index::{box_distance, extent, overlaps, Handle, Slab, SpatialIndex},
+};
 
 /**
 A PointMap is a grid subdivision of a Rectangle where each cell holds
 zero or more shapes. It speeds up the search for close proximity shapes (neighbors) by
 limiting the search to only look in the same cell, or same cell as well as adjacent cells.
+
+The cells are square, so a rectangle that is taller than it is wide gets more rows than
+columns. How many rings of cells around a shape are searched depends on the search radius,
+see [`PointMap::set_search_radius`].
 */
 #[derive(Debug, Clone)]
 pub struct PointMap<'a, T: Shape> {
     bounds: &'a Rectangle,
-    cells: Vec<Vec<T>>,
-    grid_resolution: usize,
+
+    /// Indices into {items} of the items in each cell
+    cells: Vec<Vec<usize>>,
+
+    /// The items with the cell they are in, and where in the cell
+    items: Slab<T, (usize, usize)>,
+
+    columns: usize,
+    rows: usize,
+    cell_size: f64,
+    search_radius: f64,
+
+    /// How far the bounding boxes of the items reach from their centers at most
+    reach: f64,
 }
 
-impl<'a, T: Shape + Clone + PartialEq> PointMap<'a, T> {
+impl<'a, T: Shape + Clone> PointMap<'a, T> {
     /**
     Create a new map for a given bounds with a list of cells to aid in operations
-    like finding the nearest neighbors or doing collision detections.
+    like finding the nearest neighbors or doing collision detections. The bounds are split
+    into {resolution} columns, and as many rows as it takes to keep the cells square.
 
     Example:
     ```
@@ -33,50 +55,118 @@ impl<'a, T: Shape + Clone + PartialEq> PointMap<'a, T> {
     ```
     */
     pub fn new(bounds: &'a Rectangle, resolution: usize) -> PointMap<'a, T> {
+        let columns = resolution.max(1);
+        let cell_size = if bounds.width > 0.0 {
+            bounds.width / columns as f64
+        } else {
+            bounds.height.max(1.0)
+        };
+        let rows = ((bounds.height / cell_size).ceil() as usize).max(1);
+
         PointMap {
             bounds,
-            cells: vec![Vec::with_capacity(resolution); resolution.pow(2)],
-            grid_resolution: resolution,
+            cells: vec![vec![]; columns * rows],
+            items: Slab::new(),
+            columns,
+            rows,
+            cell_size,
+            search_radius: cell_size,
+            reach: 0.0,
         }
     }
 
-    /// Insert a new shape into the grid
-    pub fn insert(&mut self, shape: T) -> Result<usize, T> {
-        let center = shape.center();
-        let i = self.get_index(&center);
+    /**
+    Set how far from a shape [`PointMap::get_neighbors`] looks when no distance is given.
+    Enough rings of cells are searched to cover the radius, so a map with small cells can
+    still find large shapes, like the wide strokes in Forces. The default is the size of
+    a single cell, which searches the cells right next to the shape.
+    */
+    pub fn set_search_radius(&mut self, radius: f64) {
+        self.search_radius = radius;
+    }
 
-        match self.cells.get_mut(i) {
-            Some(points) => {
-                points.push(shape);
-                Ok(i)
-            }
-            None => Err(shape),
-        }
+    /**
+    Insert a new shape into the grid, giving back a handle to remove or move it later,
+    or the shape if it is outside of the bounds.
+
+    Example
+    ```
+    use generative_art::shapes::{point::Point, pointmap::PointMap, rectangle::Rectangle};
+
+    let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+    let mut point_map: PointMap<'_, Point> = PointMap::new(&bounds, 10);
+
+    let handle = point_map.insert(Point(10.0, 10.0)).unwrap();
+    point_map.update(handle, Point(90.0, 90.0)).unwrap();
+    assert_eq!(point_map.nearest(&Point(100.0, 100.0)), Some(&Point(90.0, 90.0)));
+
+    assert_eq!(point_map.remove(handle), Some(Point(90.0, 90.0)));
+    assert_eq!(point_map.get(handle), None);
+    ```
+    */
+    pub fn insert(&mut self, shape: T) -> Result<Handle, T> {
+        let Some(cell) = self.get_index(&shape.center()) else {
+            return Err(shape);
+        };
+
+        self.reach = self.reach.max(reach(&shape));
+        let handle = self.items.insert(shape, (cell, 0));
+        self.attach(handle.index, cell);
+
+        Ok(handle)
     }
 
-    /// Remove a shape from the grid
-    pub fn remove(&mut self, shape: T) {
-        let center = shape.center();
-        let i = self.get_index(&center);
+    /// Remove a shape from the grid, giving it back unless it was already removed
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        self.items.get(handle)?;
+        self.detach(handle.index);
 
-        if let Some(cell) = self.cells.get_mut(i) {
-            let result = cell.iter().enumerate().find(|(_, s)| **s == shape);
+        self.items.remove(handle).map(|(item, _)| item)
+    }
 
-            if let Some((index, _)) = result {
-                cell.remove(index);
-            }
+    /// Replace a shape in the grid with {shape}, moving it to another cell if needed. The
+    /// shape is given back if the handle was removed, or if {shape} is outside of the bounds.
+    pub fn update(&mut self, handle: Handle, shape: T) -> Result<(), T> {
+        if self.items.get(handle).is_none() {
+            return Err(shape);
+        }
+        let Some(cell) = self.get_index(&shape.center()) else {
+            return Err(shape);
+        };
+
+        if cell != self.items.location(handle.index).0 {
+            self.detach(handle.index);
+            self.attach(handle.index, cell);
         }
+        self.reach = self.reach.max(reach(&shape));
+        self.items.replace(handle.index, shape);
+
+        Ok(())
+    }
+
+    /// The shape for a handle, None if it was removed
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.items.get(handle)
+    }
+
+    /// Every item in the grid with its handle, cell by cell
+    pub fn iter(&self) -> impl Iterator<Item = (Handle, &T)> {
+        self.cells.iter().flatten().filter_map(|index| {
+            self.items
+                .item(*index)
+                .map(|item| (self.items.handle(*index), item))
+        })
     }
 
     /// Get all items from the grid
     pub fn get_items(&self) -> Vec<&T> {
-        self.cells.iter().flatten().collect::<Vec<&T>>()
+        self.iter().map(|(_, item)| item).collect::<Vec<&T>>()
     }
 
     /**
     Get all neighboring items that are close to the given shape. An optional distance
     parameter can be supplied to limit the results to all items to be at most the given
-    distance away.
+    distance away, otherwise all items in the cells within the search radius are returned.
     */
     pub fn get_neighbors(&self, shape: &T, distance: Option<f64>) -> Result<Vec<T>, &str> {
         /*
@@ -110,73 +200,270 @@ impl<'a, T: Shape + Clone + PartialEq> PointMap<'a, T> {
          */
 
         let center = shape.center();
-        if !self.bounds.contains(&center) {
+        let Some(i) = self.get_index(&center) else {
             return Err("out of bounds call for this pointmap");
-        }
-        let i = self.get_index(&center);
+        };
+
+        let radius = distance.unwrap_or(0.0).max(self.search_radius);
+        let rings = ((radius / self.cell_size).ceil() as usize).max(1);
 
         let items = self
-            .get_neighboring_cells(i)
+            .get_neighboring_cells(i, rings)
             .iter()
-            .fold(vec![], |mut list, index| match self.cells.get(*index) {
-                Some(cell_items) => {
-                    cell_items
-                        .iter()
-                        .filter(|item| {
-                            if let Some(distance) = distance {
-                                return shape.center().distance_to(&item.center()) < distance;
-                            }
-                            true
-                        })
-                        .for_each(|item| list.push(item.to_owned()));
-                    list
-                }
-                None => list,
+            .fold(vec![], |mut list, index| {
+                self.cell_items(*index)
+                    .filter(|item| {
+                        if let Some(distance) = distance {
+                            return shape.center().distance_to(&item.center()) < distance;
+                        }
+                        true
+                    })
+                    .for_each(|item| list.push(item.to_owned()));
+                list
             });
 
         Ok(items)
     }
 
-    /// Find out what cell a given point is located in.
-    fn get_index(&self, point: &Point) -> usize {
-        let resolution = self.grid_resolution as f64;
-
-        let x = ((point.0 / (self.bounds.position.0 + self.bounds.width)) * resolution).floor();
-        let y = ((point.1 / (self.bounds.position.1 + self.bounds.height)) * resolution).floor();
-
-        (y * resolution + x - 1.0) as usize
-    }
-
-    /// Get all the surrounding cells for a given cell index. This is useful when considering
-    /// that shapes can spill over the edges of a cell, i.e, if a large circle is close the edge
-    /// of a cell, the origo might be inside but other points of the circle might actually be
-    /// in neighboring cells.
-    fn get_neighboring_cells(&self, index: usize) -> Vec<usize> {
-        let i = index as i32;
-        let step = (self.cells.len() as f64).sqrt() as i32;
-        let over = i - step;
-        let under = i + step;
-
-        vec![
-            over - 1,
-            over,
-            over + 1,
-            i - 1,
-            i,
-            i + 1,
-            under - 1,
-            under,
-            under + 1,
+    /**
+    All items with their center within {radius} of {point}, the point itself can be
+    outside of the bounds.
+
+    Example
+    ```
+    use generative_art::shapes::{point::Point, pointmap::PointMap, rectangle::Rectangle};
+
+    let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+    let mut point_map: PointMap<'_, Point> = PointMap::new(&bounds, 10);
+    let _ = point_map.insert(Point(10.0, 10.0));
+    let _ = point_map.insert(Point(50.0, 50.0));
+
+    let close: Vec<&Point> = point_map.within_radius(&Point(0.0, 0.0), 20.0).collect();
+    assert_eq!(close, vec![&Point(10.0, 10.0)]);
+    ```
+    */
+    pub fn within_radius<'b>(
+        &'b self,
+        point: &'b Point,
+        radius: f64,
+    ) -> impl Iterator<Item = &'b T> + 'b {
+        self.around(point, radius)
+            .filter(move |item| item.center().distance_to(point) <= radius)
+    }
+
+    /// The item with its center closest to {point}, None if the map is empty.
+    pub fn nearest(&self, point: &Point) -> Option<&T> {
+        self.k_nearest(point, 1).into_iter().next()
+    }
+
+    /**
+    The {k} items with their centers closest to {point}, closest first. Fewer items are
+    returned if the map doesn't have {k} items. Rings of cells are searched further and
+    further out, until no cell that is left can have anything closer.
+    */
+    pub fn k_nearest(&self, point: &Point, k: usize) -> Vec<&T> {
+        self.closest(point, k, 0.0, |item| item.center().distance_to(point))
+    }
+
+    /// The items in the cells that {point} is at most {radius} away from
+    fn around<'b>(&'b self, point: &Point, radius: f64) -> impl Iterator<Item = &'b T> + 'b {
+        let (column, row) = self.cell_position(point);
+        let rings = (radius.max(0.0) / self.cell_size).ceil() as usize;
+
+        (0..=rings)
+            .flat_map(move |ring| self.ring_cells(column, row, ring))
+            .flat_map(|i| self.cell_items(i))
+    }
+
+    /**
+    The {k} items that are the smallest {distance} away, where an item is never closer
+    than its center minus {reach}. Rings of cells are searched further and further out,
+    until no cell that is left can have anything closer.
+    */
+    fn closest(
+        &self,
+        point: &Point,
+        k: usize,
+        reach: f64,
+        distance: impl Fn(&T) -> f64,
+    ) -> Vec<&T> {
+        if k == 0 {
+            return vec![];
+        }
+
+        let (column, row) = self.cell_position(point);
+        let last_ring = [
+            column,
+            self.columns as isize - 1 - column,
+            row,
+            self.rows as isize - 1 - row,
         ]
+        .iter()
+        .map(|cells| cells.unsigned_abs())
+        .max()
+        .unwrap_or(0);
+
+        let mut found: Vec<(f64, &T)> = vec![];
+
+        for ring in 0..=last_ring {
+            // Anything in this ring or further out is at least this far away
+            let closest_possible = (ring as f64 - 1.0) * self.cell_size - reach;
+            if found.len() == k && found[k - 1].0 <= closest_possible {
+                break;
+            }
+
+            found.extend(
+                self.ring_cells(column, row, ring)
+                    .into_iter()
+                    .flat_map(|i| self.cell_items(i).map(|item| (distance(item), item))),
+            );
+            found.sort_by(|a, b| a.0.total_cmp(&b.0));
+            found.truncate(k);
+        }
+
+        found.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// The items in the cell at {index}
+    fn cell_items(&self, index: usize) -> impl Iterator<Item = &T> {
+        self.cells[index].iter().filter_map(|i| self.items.item(*i))
+    }
+
+    /// Add the entry at {index} to the end of {cell}
+    fn attach(&mut self, index: usize, cell: usize) {
+        self.cells[cell].push(index);
+        self.items
+            .set_location(index, (cell, self.cells[cell].len() - 1));
+    }
+
+    /// Take the entry at {index} out of its cell, by moving the last entry of the cell into
+    /// its place, so nothing else has to move.
+    fn detach(&mut self, index: usize) {
+        let (cell, slot) = self.items.location(index);
+
+        self.cells[cell].swap_remove(slot);
+        if let Some(moved) = self.cells[cell].get(slot) {
+            self.items.set_location(*moved, (cell, slot));
+        }
+    }
+
+    /// The column and row {point} would be in if the grid went on forever
+    fn cell_position(&self, point: &Point) -> (isize, isize) {
+        (
+            ((point.0 - self.bounds.position.0) / self.cell_size).floor() as isize,
+            ((point.1 - self.bounds.position.1) / self.cell_size).floor() as isize,
+        )
+    }
+
+    /// The cells exactly {ring} cells away from ({column}, {row}) that are inside the grid
+    fn ring_cells(&self, column: isize, row: isize, ring: usize) -> Vec<usize> {
+        let ring = ring as isize;
+        let (columns, rows) = (self.columns as isize, self.rows as isize);
+
+        (row - ring..=row + ring)
+            .filter(|y| (0..rows).contains(y))
+            .flat_map(|y| {
+                // Only the ends of the rows in between the top and bottom of the ring
+                let step = if y == row - ring || y == row + ring {
+                    1
+                } else {
+                    (2 * ring).max(1)
+                };
+
+                (column - ring..=column + ring)
+                    .step_by(step as usize)
+                    .filter(|x| (0..columns).contains(x))
+                    .map(move |x| (y * columns + x) as usize)
+            })
+            .collect()
+    }
+
+    /// Find out what cell a given point is located in, None if it is outside the bounds.
+    fn get_index(&self, point: &Point) -> Option<usize> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        // Rounding can put points close to the far edges past the last row or column
+        let column = ((point.0 - self.bounds.position.0) / self.cell_size).floor() as usize;
+        let row = ((point.1 - self.bounds.position.1) / self.cell_size).floor() as usize;
+
+        Some(row.min(self.rows - 1) * self.columns + column.min(self.columns - 1))
+    }
+
+    /// Get all the surrounding cells for a given cell index, {rings} cells out in each
+    /// direction. This is useful when considering that shapes can spill over the edges of a
+    /// cell, i.e, if a large circle is close the edge of a cell, the origo might be inside
+    /// but other points of the circle might actually be in neighboring cells.
+    fn get_neighboring_cells(&self, index: usize, rings: usize) -> Vec<usize> {
+        let (column, row) = (index % self.columns, index / self.columns);
+
+        let rows = row.saturating_sub(rings)..=(row + rings).min(self.rows - 1);
+        let columns = column.saturating_sub(rings)..=(column + rings).min(self.columns - 1);
+
+        rows.flat_map(|y| columns.clone().map(move |x| y * self.columns + x))
+            .collect()
+    }
+}
+
+/// The items are found by their centers, so the cells are searched further out by how far
+/// the largest bounding box reaches from its center.
+impl<'a, T: Shape + Clone> SpatialIndex<T> for PointMap<'a, T> {
+    fn insert(&mut self, shape: T) -> Result<Handle, T> {
+        PointMap::insert(self, shape)
+    }
+
+    fn remove(&mut self, handle: Handle) -> Option<T> {
+        PointMap::remove(self, handle)
+    }
+
+    fn query_rectangle(&self, area: &Rectangle) -> Vec<&T> {
+        let (x1, y1) = self.cell_position(&area.position.offset(-self.reach, -self.reach));
+        let (x2, y2) = self.cell_position(
+            &area
+                .position
+                .offset(area.width + self.reach, area.height + self.reach),
+        );
+
+        (y1.max(0)..=y2.min(self.rows as isize - 1))
+            .flat_map(|y| {
+                (x1.max(0)..=x2.min(self.columns as isize - 1))
+                    .map(move |x| (y * self.columns as isize + x) as usize)
+            })
+            .flat_map(|i| self.cell_items(i))
+            .filter(|item| overlaps(&extent(*item), area))
+            .collect()
+    }
+
+    fn query_radius(&self, point: &Point, radius: f64) -> Vec<&T> {
+        self.around(point, radius + self.reach)
+            .filter(|item| box_distance(&extent(*item), point) <= radius)
+            .collect()
+    }
+
+    fn nearest(&self, point: &Point) -> Option<&T> {
+        self.closest(point, 1, self.reach, |item| {
+            box_distance(&extent(item), point)
+        })
         .into_iter()
-        .filter(|cell| *cell > 0 || (*cell as usize) < self.cells.len())
-        .map(|cell| cell as usize)
-        .collect::<Vec<usize>>()
+        .next()
     }
 }
 
+/// How far the bounding box of {shape} reaches from its center
+fn reach<T: Shape>(shape: &T) -> f64 {
+    let (center, bounds) = (shape.center(), extent(shape));
+    let dx = (center.0 - bounds.position.0).max(bounds.position.0 + bounds.width - center.0);
+    let dy = (center.1 - bounds.position.1).max(bounds.position.1 + bounds.height - center.1);
+
+    dx.max(0.0).hypot(dy.max(0.0))
+}
+
 #[cfg(test)]
 mod test {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
     use crate::shapes::{circle::Circle, point::Point, pointmap::PointMap, rectangle::Rectangle};
 
     #[test]
@@ -188,11 +475,25 @@ mod test {
             color: Rectangle::default().color,
         };
         let point_map: PointMap<'_, Circle> = PointMap::new(&bounds, 10);
-        assert_eq!(point_map.get_index(&Point(9.0, 0.0)), 0);
-        assert_eq!(point_map.get_index(&Point(11.0, 0.0)), 0);
-        assert_eq!(point_map.get_index(&Point(20.0, 0.0)), 1);
-        assert_eq!(point_map.get_index(&Point(34.0, 0.0)), 2);
-        assert_eq!(point_map.get_index(&Point(99.999, 0.0)), 8);
+        assert_eq!(point_map.get_index(&Point(9.0, 0.0)), Some(0));
+        assert_eq!(point_map.get_index(&Point(11.0, 0.0)), Some(1));
+        assert_eq!(point_map.get_index(&Point(20.0, 0.0)), Some(2));
+        assert_eq!(point_map.get_index(&Point(34.0, 0.0)), Some(3));
+        assert_eq!(point_map.get_index(&Point(99.999, 0.0)), Some(9));
+        assert_eq!(point_map.get_index(&Point(99.9, 99.9)), Some(99));
+        assert_eq!(point_map.get_index(&Point(100.1, 0.0)), None);
+    }
+
+    #[test]
+    fn get_index_offset_bounds() {
+        let bounds = Rectangle::new(Point(-50.0, 200.0), 100.0, 150.0);
+        let point_map: PointMap<'_, Circle> = PointMap::new(&bounds, 10);
+
+        assert_eq!(point_map.rows, 15);
+        assert_eq!(point_map.get_index(&Point(-50.0, 200.0)), Some(0));
+        assert_eq!(point_map.get_index(&Point(-35.0, 215.0)), Some(11));
+        assert_eq!(point_map.get_index(&Point(45.0, 345.0)), Some(149));
+        assert_eq!(point_map.get_index(&Point(0.0, 0.0)), None);
     }
 
     #[test]
@@ -205,9 +506,10 @@ mod test {
         };
         let mut point_map: PointMap<'_, Circle> = PointMap::new(&bounds, 10);
         let circle = Circle::new(Point(11.0, 11.0), 10.0);
-        if let Ok(result) = point_map.insert(circle) {
-            assert_eq!(result, 10);
-        }
+        let handle = point_map.insert(circle).unwrap();
+
+        assert_eq!(point_map.get(handle), Some(&circle));
+        assert_eq!(point_map.items.location(handle.index).0, 11);
     }
 
     #[test]
@@ -294,9 +596,126 @@ mod test {
 
         let map: PointMap<'_, Point> = PointMap::new(&bounds, 10);
 
-        let indicies = map.get_neighboring_cells(25);
-        println!("{}", map.cells.len());
-
+        let indicies = map.get_neighboring_cells(25, 1);
         assert_eq!(indicies, vec![14, 15, 16, 24, 25, 26, 34, 35, 36]);
+
+        // No wrapping around to the other side of the grid
+        assert_eq!(map.get_neighboring_cells(0, 1), vec![0, 1, 10, 11]);
+        assert_eq!(map.get_neighboring_cells(19, 1), vec![8, 9, 18, 19, 28, 29]);
+        assert_eq!(map.get_neighboring_cells(0, 2).len(), 9);
+    }
+
+    #[test]
+    fn search_radius_covers_more_cells() {
+        let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+        let mut point_map: PointMap<'_, Circle> = PointMap::new(&bounds, 10);
+        let circle = Circle::new(Point(5.0, 5.0), 5.0);
+        let far = Circle::new(Point(45.0, 5.0), 5.0);
+
+        let _ = point_map.insert(circle);
+        let _ = point_map.insert(far);
+
+        assert_eq!(point_map.get_neighbors(&circle, None).unwrap().len(), 1);
+        assert_eq!(
+            point_map.get_neighbors(&circle, Some(45.)).unwrap().len(),
+            2
+        );
+
+        point_map.set_search_radius(40.);
+        assert_eq!(point_map.get_neighbors(&circle, None).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn ring_cells() {
+        let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+        let map: PointMap<'_, Point> = PointMap::new(&bounds, 10);
+
+        assert_eq!(map.ring_cells(5, 2, 0), vec![25]);
+        assert_eq!(
+            map.ring_cells(5, 2, 1),
+            vec![14, 15, 16, 24, 26, 34, 35, 36]
+        );
+        assert_eq!(map.ring_cells(0, 0, 1), vec![1, 10, 11]);
+        assert_eq!(map.ring_cells(-3, 0, 3), vec![0, 10, 20, 30]);
+    }
+
+    #[test]
+    fn nearest_neighbors() {
+        let bounds = Rectangle::new(Point(-20.0, 10.0), 100.0, 60.0);
+        let mut map: PointMap<'_, Point> = PointMap::new(&bounds, 10);
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        let points: Vec<Point> = (0..200)
+            .map(|_| {
+                Point(
+                    rng.gen_range(bounds.x_range()),
+                    rng.gen_range(bounds.y_range()),
+                )
+            })
+            .collect();
+        for point in &points {
+            let _ = map.insert(*point);
+        }
+
+        for query in [Point(0.0, 20.0), Point(79.0, 69.0), Point(-100.0, 300.0)] {
+            let mut sorted = points.clone();
+            sorted.sort_by(|a, b| a.distance_to(&query).total_cmp(&b.distance_to(&query)));
+
+            assert_eq!(map.nearest(&query), Some(&sorted[0]));
+            assert_eq!(
+                map.k_nearest(&query, 7),
+                sorted.iter().take(7).collect::<Vec<&Point>>()
+            );
+
+            let mut close: Vec<&Point> = map.within_radius(&query, 25.0).collect();
+            close.sort_by(|a, b| a.distance_to(&query).total_cmp(&b.distance_to(&query)));
+            let expected: Vec<&Point> = sorted
+                .iter()
+                .take_while(|p| p.distance_to(&query) <= 25.0)
+                .collect();
+            assert_eq!(close, expected);
+        }
+
+        assert_eq!(map.k_nearest(&Point(0.0, 0.0), 500).len(), 200);
+    }
+
+    #[test]
+    fn remove_and_update() {
+        let bounds = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
+        let mut map: PointMap<'_, Point> = PointMap::new(&bounds, 10);
+
+        let handles: Vec<_> = (0..5)
+            .map(|i| map.insert(Point(i as f64, 1.0)).unwrap())
+            .collect();
+
+        // Removing from the middle of a cell keeps the other handles working
+        assert_eq!(map.remove(handles[1]), Some(Point(1.0, 1.0)));
+        assert_eq!(map.remove(handles[1]), None);
+        assert_eq!(map.get(handles[4]), Some(&Point(4.0, 1.0)));
+
+        // Moving within a cell and to another cell
+        assert_eq!(map.update(handles[2], Point(2.5, 1.0)), Ok(()));
+        assert_eq!(map.update(handles[4], Point(55.0, 55.0)), Ok(()));
+        assert_eq!(
+            map.update(handles[1], Point(5.0, 5.0)),
+            Err(Point(5.0, 5.0))
+        );
+        assert_eq!(
+            map.update(handles[0], Point(500.0, 5.0)),
+            Err(Point(500.0, 5.0))
+        );
+
+        assert_eq!(
+            map.within_radius(&Point(0.0, 0.0), 10.0).count(),
+            3,
+            "points 0, 2 and 3 are left close to the origin"
+        );
+        assert_eq!(map.nearest(&Point(60.0, 60.0)), Some(&Point(55.0, 55.0)));
+
+        // Reused entries don't bring back old handles
+        let handle = map.insert(Point(7.0, 7.0)).unwrap();
+        assert_eq!(handle.index, handles[1].index);
+        assert_eq!(map.get(handles[1]), None);
+        assert_eq!(map.get_items().len(), 5);
     }
 }
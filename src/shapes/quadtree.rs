@@ -0,0 +1,263 @@
+use super::{
+    point::Point,
+    rectangle::Rectangle,
+    shape::Shape,
+    spatial_index::{box_distance, encloses, extent, overlaps, Handle, Slab, SpatialIndex},
+};
+
+/// How many shapes a quadrant holds before it is split up
+const CAPACITY: usize = 8;
+
+/// How many times the bounds can be split, to stop at shapes piled up at the same spot
+const MAX_DEPTH: usize = 12;
+
+/**
+A QuadTree splits its bounds into four quadrants once too many shapes end up in it, and keeps
+on splitting the quadrants where shapes crowd together. Every shape is kept in the smallest
+quadrant its bounding box fits in, so large shapes stay close to the top of the tree while
+small shapes sink down to where they are, which suits drawings with shapes of very different
+sizes. Shapes are only accepted when their center is within the bounds.
+
+Example
+```
+use generative_art::shapes::{
+    circle::Circle, point::Point, quadtree::QuadTree, rectangle::Rectangle,
+    spatial_index::SpatialIndex,
+};
+
+let mut tree = QuadTree::new(Rectangle::new(Point(0.0, 0.0), 1000.0, 1000.0));
+let large = tree.insert(Circle::new(Point(300.0, 300.0), 250.0)).unwrap();
+let _ = tree.insert(Circle::new(Point(900.0, 900.0), 40.0));
+
+let area = Rectangle::new(Point(500.0, 500.0), 100.0, 100.0);
+assert_eq!(tree.query_rectangle(&area).len(), 1);
+
+tree.remove(large);
+assert!(tree.query_rectangle(&area).is_empty());
+```
+*/
+#[derive(Debug, Clone)]
+pub struct QuadTree<T: Shape> {
+    nodes: Vec<Node>,
+
+    /// The shapes with the node they are kept in
+    items: Slab<T, usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    bounds: Rectangle,
+    depth: usize,
+
+    /// Indices into {items} of the shapes kept in this node
+    items: Vec<usize>,
+
+    /// The first of the four quadrants, which are next to each other in {nodes}
+    children: Option<usize>,
+}
+
+impl<T: Shape> QuadTree<T> {
+    /// Create an empty tree for shapes with their center within {bounds}
+    pub fn new(bounds: Rectangle) -> QuadTree<T> {
+        QuadTree {
+            nodes: vec![Node {
+                bounds,
+                depth: 0,
+                items: vec![],
+                children: None,
+            }],
+            items: Slab::new(),
+        }
+    }
+
+    /// The shape for a handle, None if it was removed
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.items.get(handle)
+    }
+
+    /// The four quadrants of {node}, if it has been split
+    fn children(&self, node: usize) -> impl Iterator<Item = usize> {
+        self.nodes[node].children.into_iter().flat_map(|i| i..i + 4)
+    }
+
+    /// The shapes kept in {node}
+    fn node_items(&self, node: usize) -> impl Iterator<Item = &T> {
+        self.nodes[node]
+            .items
+            .iter()
+            .filter_map(|i| self.items.item(*i))
+    }
+
+    /// The deepest node below {node} that {area} fits in
+    fn descend(&self, mut node: usize, area: &Rectangle) -> usize {
+        while let Some(child) = self
+            .children(node)
+            .find(|child| encloses(&self.nodes[*child].bounds, area))
+        {
+            node = child;
+        }
+
+        node
+    }
+
+    /// Split {node} into quadrants once it holds too many shapes, and move every shape that
+    /// fits into a quadrant down.
+    fn split_when_full(&mut self, node: usize) {
+        let Node {
+            bounds,
+            depth,
+            ref items,
+            children,
+        } = self.nodes[node];
+        if children.is_some() || items.len() <= CAPACITY || depth >= MAX_DEPTH {
+            return;
+        }
+
+        let first = self.nodes.len();
+        let (width, height) = (bounds.width / 2.0, bounds.height / 2.0);
+        for (x, y) in [(0.0, 0.0), (width, 0.0), (0.0, height), (width, height)] {
+            self.nodes.push(Node {
+                bounds: Rectangle::new(bounds.position.offset(x, y), width, height),
+                depth: depth + 1,
+                items: vec![],
+                children: None,
+            });
+        }
+        self.nodes[node].children = Some(first);
+
+        let items = std::mem::take(&mut self.nodes[node].items);
+        for index in items {
+            let target = match self.items.item(index) {
+                Some(item) => self.descend(node, &extent(item)),
+                None => node,
+            };
+
+            self.nodes[target].items.push(index);
+            self.items.set_location(index, target);
+        }
+
+        for child in first..first + 4 {
+            self.split_when_full(child);
+        }
+    }
+
+    /// Every node, from the top down, that {visit} is true for, skipping the quadrants of
+    /// nodes that it is false for. Shapes in a quadrant never reach outside of it, only the
+    /// top of the tree can hold shapes that stick out of the bounds, so it is always visited.
+    fn visit(&self, visit: impl Fn(&Rectangle) -> bool) -> Vec<usize> {
+        let mut visited = vec![];
+        let mut stack = vec![0];
+
+        while let Some(node) = stack.pop() {
+            if node == 0 || visit(&self.nodes[node].bounds) {
+                visited.push(node);
+                stack.extend(self.children(node));
+            }
+        }
+
+        visited
+    }
+
+    fn nearest_in<'b>(&'b self, node: usize, point: &Point, best: &mut Option<(f64, &'b T)>) {
+        for item in self.node_items(node) {
+            let distance = box_distance(&extent(item), point);
+            if best.is_none_or(|(closest, _)| distance < closest) {
+                *best = Some((distance, item));
+            }
+        }
+
+        let mut children: Vec<(f64, usize)> = self
+            .children(node)
+            .map(|child| (box_distance(&self.nodes[child].bounds, point), child))
+            .collect();
+        children.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        for (distance, child) in children {
+            if best.is_none_or(|(closest, _)| distance < closest) {
+                self.nearest_in(child, point, best);
+            }
+        }
+    }
+}
+
+impl<T: Shape> SpatialIndex<T> for QuadTree<T> {
+    fn insert(&mut self, shape: T) -> Result<Handle, T> {
+        if !self.nodes[0].bounds.contains(&shape.center()) {
+            return Err(shape);
+        }
+
+        let node = self.descend(0, &extent(&shape));
+        let handle = self.items.insert(shape, node);
+        self.nodes[node].items.push(handle.index);
+        self.split_when_full(node);
+
+        Ok(handle)
+    }
+
+    fn remove(&mut self, handle: Handle) -> Option<T> {
+        self.items.get(handle)?;
+
+        let items = &mut self.nodes[self.items.location(handle.index)].items;
+        if let Some(slot) = items.iter().position(|i| *i == handle.index) {
+            items.swap_remove(slot);
+        }
+
+        self.items.remove(handle).map(|(item, _)| item)
+    }
+
+    fn query_rectangle(&self, area: &Rectangle) -> Vec<&T> {
+        self.visit(|bounds| overlaps(bounds, area))
+            .into_iter()
+            .flat_map(|node| self.node_items(node))
+            .filter(|item| overlaps(&extent(*item), area))
+            .collect()
+    }
+
+    fn query_radius(&self, point: &Point, radius: f64) -> Vec<&T> {
+        self.visit(|bounds| box_distance(bounds, point) <= radius)
+            .into_iter()
+            .flat_map(|node| self.node_items(node))
+            .filter(|item| box_distance(&extent(*item), point) <= radius)
+            .collect()
+    }
+
+    fn nearest(&self, point: &Point) -> Option<&T> {
+        let mut best = None;
+        self.nearest_in(0, point, &mut best);
+
+        best.map(|(_, item)| item)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{
+        point::Point, quadtree::QuadTree, rectangle::Rectangle, spatial_index::SpatialIndex,
+    };
+
+    #[test]
+    fn small_shapes_sink_down() {
+        let mut tree = QuadTree::new(Rectangle::new(Point(0., 0.), 100., 100.));
+        let _ = tree.insert(Rectangle::new(Point(10., 10.), 80., 80.));
+
+        let handles: Vec<_> = (0..20)
+            .map(|i| {
+                tree.insert(Rectangle::new(Point(i as f64, 1.), 0.5, 0.5))
+                    .unwrap()
+            })
+            .collect();
+
+        assert_eq!(tree.nodes[0].items.len(), 1);
+        assert!(tree.nodes[tree.items.location(handles[0].index)].depth > 1);
+
+        assert!(tree
+            .insert(Rectangle::new(Point(150., 150.), 100., 100.))
+            .is_err());
+        assert_eq!(tree.query_radius(&Point(0., 0.), 2.).len(), 2);
+        assert_eq!(tree.nearest(&Point(50., 50.)).map(|r| r.width), Some(80.));
+        assert_eq!(
+            tree.get(handles[3]).map(|r| r.position),
+            Some(Point(3., 1.))
+        );
+    }
+}
@@ -23,7 +23,7 @@ Example
 use generative_art::{shapes::{rectangle::Rectangle, point::Point}, svg::document::Document, palette::color::Color};
 
 let mut rect = Rectangle::new(Point(0.0, 0.0), 100.0, 100.0);
-rect.set_color(Color::Hex("#f00"));
+rect.set_color(Color::hex("#f00"));
 
 let mut svg = Document::new("my_doc", rect);
 
@@ -85,6 +85,26 @@ impl Rectangle {
         self.width * self.height
     }
 
+    /// The overlapping area of this rectangle and {other}, or None if they don't overlap.
+    /// The color of this rectangle is kept.
+    pub fn intersection(&self, other: &Rectangle) -> Option<Rectangle> {
+        let x1 = self.position.0.max(other.position.0);
+        let y1 = self.position.1.max(other.position.1);
+        let x2 = (self.position.0 + self.width).min(other.position.0 + other.width);
+        let y2 = (self.position.1 + self.height).min(other.position.1 + other.height);
+
+        if x2 <= x1 || y2 <= y1 {
+            return None;
+        }
+
+        Some(Rectangle {
+            position: Point(x1, y1),
+            width: x2 - x1,
+            height: y2 - y1,
+            color: self.color,
+        })
+    }
+
     /// Returns a range that starts at the x position of the rectangle
     /// and ends on the right side of the rectangle.
     pub fn x_range(&self) -> Range<f64> {
@@ -99,7 +119,7 @@ impl Rectangle {
 
     /// Converts this rectangle to a [`Path`]. Useful for path wobbling.
     pub fn to_path(&self, style: PathStyle) -> Path {
-        let points = vec![
+        let points = [
             (self.position.0, self.position.1),
             (self.position.0 + self.width, self.position.1),
             (self.position.0 + self.width, self.position.1 + self.height),
@@ -185,10 +205,17 @@ impl Shape for Rectangle {
         self.x_range().contains(&point.0) && self.y_range().contains(&point.1)
     }
 
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        match self.intersection(bounds) {
+            Some(rect) => rect.as_svg(),
+            None => String::from(""),
+        }
+    }
+
     fn center(&self) -> Point {
         Point(
-            (self.position.0 + self.width) / 2.0,
-            (self.position.1 + self.height) / 2.0,
+            self.position.0 + self.width / 2.0,
+            self.position.1 + self.height / 2.0,
         )
     }
 
@@ -200,6 +227,58 @@ impl Shape for Rectangle {
             color: None,
         })
     }
+
+    fn distance_to(&self, point: &Point) -> f64 {
+        let d_x = (point.0 - (self.position.0 + self.width / 2.0)).abs() - self.width / 2.0;
+        let d_y = (point.1 - (self.position.1 + self.height / 2.0)).abs() - self.height / 2.0;
+
+        let outside = (d_x.max(0.0).powi(2) + d_y.max(0.0).powi(2)).sqrt();
+        let inside = d_x.max(d_y).min(0.0);
+
+        outside + inside
+    }
+
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        (self.width + self.height) * 2.0
+    }
+
+    fn to_polygon(&self, _tolerance: f64) -> Path {
+        self.to_path(PathStyle {
+            color: self.color,
+            ..Default::default()
+        })
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(Rectangle {
+            position: self.position.offset(x, y),
+            ..*self
+        })
+    }
+
+    /// Rectangles are always aligned with the axes, so the result is a [`Path`].
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        self.to_polygon(0.0).rotate(angle, origin)
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        let a = self.position.scale(factor, origin);
+        let b = self
+            .position
+            .offset(self.width, self.height)
+            .scale(factor, origin);
+
+        Box::new(Rectangle {
+            position: Point(a.0.min(b.0), a.1.min(b.1)),
+            width: (b.0 - a.0).abs(),
+            height: (b.1 - a.1).abs(),
+            color: self.color,
+        })
+    }
 }
 
 impl Default for Rectangle {
@@ -249,6 +328,38 @@ mod test {
         assert!(!rect.contains(&point));
     }
 
+    #[test]
+    fn transforms() {
+        let rect = Rectangle::new(Point(0., 0.), 20.0, 10.0);
+
+        let rotated = rect.rotate(std::f64::consts::FRAC_PI_2, &Point(0., 0.));
+        assert!((rotated.area() - 200.).abs() < 1e-9);
+        assert_eq!(
+            rotated
+                .bounding_box()
+                .map(|b| (b.position.0.round(), b.width.round())),
+            Some((-10., 10.))
+        );
+
+        assert_eq!(
+            Shape::scale(&rect, -2., &Point(0., 0.)).bounding_box(),
+            Some(Rectangle::new(Point(-40., -20.), 40.0, 20.0))
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Rectangle::new(Point(0., 0.), 20.0, 20.0);
+        let b = Rectangle::new(Point(10., 5.), 20.0, 5.0);
+        let c = Rectangle::new(Point(30., 30.), 20.0, 5.0);
+
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rectangle::new(Point(10., 5.), 10.0, 5.0))
+        );
+        assert_eq!(a.intersection(&c), None);
+    }
+
     #[test]
     fn scale_rect_up() {
         let rect = Rectangle {
@@ -312,6 +423,9 @@ mod test {
     fn test_center_other() {
         let rect = Rectangle::new(Point(50., 0.), 100.0, 100.0);
 
-        assert_eq!(rect.center(), Point(75.0, 50.0));
+        assert_eq!(rect.center(), Point(100.0, 50.0));
+
+        let rect = Rectangle::new(Point(20., 40.), 10.0, 30.0);
+        assert_eq!(rect.center(), Point(25.0, 55.0));
     }
 }
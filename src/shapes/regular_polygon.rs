@@ -0,0 +1,200 @@
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use crate::palette::color::Color;
+
+use super::{
+    path::{Path, PathStyle},
+    point::Point,
+    rectangle::Rectangle,
+    shape::{scale_turn, Shape},
+};
+
+/// A polygon where all sides have the same length, like a triangle, square or hexagon
+#[derive(Debug, Clone, Copy)]
+pub struct RegularPolygon {
+    /// Center of the polygon
+    pub center: Point,
+
+    /// Distance from the center to each corner
+    pub radius: f64,
+
+    /// Number of sides, at least 3
+    pub sides: usize,
+
+    /// Rotation around the center in radians, the first corner points straight up when this is 0
+    pub rotation: f64,
+
+    /// Fill color for the polygon
+    color: Option<Color>,
+}
+
+impl RegularPolygon {
+    /// Create a new polygon around {center} with the given number of {sides}, where each corner
+    /// is {radius} away from the center.
+    ///
+    /// ```
+    /// use generative_art::shapes::{point::Point, regular_polygon::RegularPolygon};
+    /// let hexagon = RegularPolygon::new(Point(0.0, 0.0), 10.0, 6);
+    /// ```
+    pub fn new(center: Point, radius: f64, sides: usize) -> RegularPolygon {
+        RegularPolygon {
+            center,
+            radius,
+            sides: sides.max(3),
+            rotation: 0.0,
+            color: None,
+        }
+    }
+
+    /// Set the fill color of the polygon
+    pub fn set_color(&mut self, color: Color) {
+        self.color = Some(color);
+    }
+
+    /// The corners of the polygon, going clockwise from the top
+    pub fn vertices(&self) -> Vec<Point> {
+        let sides = self.sides.max(3);
+
+        (0..sides)
+            .map(|i| {
+                let angle = self.rotation - FRAC_PI_2 + i as f64 / sides as f64 * TAU;
+                Point(
+                    self.center.0 + angle.cos() * self.radius,
+                    self.center.1 + angle.sin() * self.radius,
+                )
+            })
+            .collect()
+    }
+
+    /// Converts this polygon to a closed [`Path`]
+    pub fn to_path(&self, style: PathStyle) -> Path {
+        let mut points = self.vertices();
+        points.push(points[0]);
+
+        Path::new(points, style)
+    }
+}
+
+impl Shape for RegularPolygon {
+    fn as_svg(&self) -> String {
+        self.to_path(PathStyle {
+            color: self.color,
+            ..Default::default()
+        })
+        .as_svg()
+    }
+
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        self.to_path(PathStyle {
+            color: self.color,
+            ..Default::default()
+        })
+        .as_clipped_svg(bounds)
+    }
+
+    fn center(&self) -> Point {
+        self.center
+    }
+
+    fn bounding_box(&self) -> Option<Rectangle> {
+        self.to_path(Default::default()).bounding_box()
+    }
+
+    fn contains(&self, point: &Point) -> bool {
+        fan_contains(&self.center, &self.vertices(), point)
+    }
+
+    fn area(&self) -> f64 {
+        let sides = self.sides.max(3) as f64;
+        sides / 2.0 * self.radius.powi(2) * (TAU / sides).sin()
+    }
+
+    fn perimeter(&self) -> f64 {
+        let sides = self.sides.max(3) as f64;
+        sides * 2.0 * self.radius * (PI / sides).sin()
+    }
+
+    fn to_polygon(&self, _tolerance: f64) -> Path {
+        self.to_path(PathStyle {
+            color: self.color,
+            ..Default::default()
+        })
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(RegularPolygon {
+            center: self.center.offset(x, y),
+            ..*self
+        })
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(RegularPolygon {
+            center: self.center.rotate(angle, origin),
+            rotation: self.rotation + angle,
+            ..*self
+        })
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        let turn = scale_turn(factor);
+
+        Box::new(RegularPolygon {
+            center: self.center.scale(factor, origin),
+            radius: self.radius * factor.abs(),
+            rotation: self.rotation + turn,
+            ..*self
+        })
+    }
+}
+
+/// True if {point} is inside one of the triangles between {center} and each pair of neighboring
+/// {vertices}, which is exact for shapes where every edge can be seen from the center.
+pub(super) fn fan_contains(center: &Point, vertices: &[Point], point: &Point) -> bool {
+    let side = |a: &Point, b: &Point| (b.0 - a.0) * (point.1 - a.1) - (b.1 - a.1) * (point.0 - a.0);
+
+    (0..vertices.len()).any(|i| {
+        let a = &vertices[i];
+        let b = &vertices[(i + 1) % vertices.len()];
+        let sides = [side(center, a), side(a, b), side(b, center)];
+
+        !(sides.iter().any(|s| *s < 0.0) && sides.iter().any(|s| *s > 0.0))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{point::Point, shape::Shape};
+
+    use super::RegularPolygon;
+
+    #[test]
+    fn square_vertices() {
+        let square = RegularPolygon::new(Point(0., 0.), 10., 4);
+        let vertices: Vec<Point> = square
+            .vertices()
+            .iter()
+            .map(|p| Point(p.0.round(), p.1.round()))
+            .collect();
+
+        assert_eq!(
+            vertices,
+            vec![
+                Point(0., -10.),
+                Point(10., 0.),
+                Point(0., 10.),
+                Point(-10., 0.)
+            ]
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let triangle = RegularPolygon::new(Point(0., 0.), 10., 3);
+
+        assert!(triangle.contains(&Point(0., 0.)));
+        assert!(triangle.contains(&Point(0., -9.)));
+        assert!(!triangle.contains(&Point(0., 6.)));
+        assert!(!triangle.contains(&Point(8., 0.)));
+    }
+}
@@ -0,0 +1,387 @@
+use super::{
+    point::Point,
+    rectangle::Rectangle,
+    shape::Shape,
+    spatial_index::{box_distance, extent, overlaps, union, Handle, Slab, SpatialIndex},
+};
+
+/// How many shapes or nodes a node holds before it is split in two
+const MAX_CHILDREN: usize = 8;
+
+/**
+An RTree groups shapes that are close together into boxes, and those boxes into larger boxes,
+until everything is in one box at the top. Unlike a grid or a
+[`QuadTree`](super::quadtree::QuadTree) it doesn't need any bounds up front, and the boxes
+follow the shapes, which suits large or long shapes that would be cut up by a grid.
+
+Example
+```
+use generative_art::shapes::{
+    path::Path, point::Point, rtree::RTree, spatial_index::SpatialIndex,
+};
+
+let mut tree = RTree::new();
+for i in 0..10 {
+    let y = i as f64 * 20.0;
+    let _ = tree.insert(Path::new(
+        vec![Point(0.0, y), Point(1000.0, y + 10.0)],
+        Default::default(),
+    ));
+}
+
+let closest = tree.nearest(&Point(500.0, 52.0)).unwrap();
+assert_eq!(closest.points[0], Point(0.0, 40.0));
+```
+*/
+#[derive(Debug, Clone)]
+pub struct RTree<T: Shape> {
+    nodes: Vec<Node>,
+    root: usize,
+
+    /// Nodes that were emptied out and can be reused
+    free_nodes: Vec<usize>,
+
+    /// The shapes with the leaf they are kept in
+    items: Slab<T, usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    /// The box around everything in this node, None when it is empty
+    bounds: Option<Rectangle>,
+    parent: Option<usize>,
+    leaf: bool,
+
+    /// Indices into {items} for leaves, or into {nodes} otherwise
+    children: Vec<usize>,
+}
+
+impl<T: Shape> RTree<T> {
+    /// Create an empty tree
+    pub fn new() -> RTree<T> {
+        RTree {
+            nodes: vec![Node {
+                bounds: None,
+                parent: None,
+                leaf: true,
+                children: vec![],
+            }],
+            root: 0,
+            free_nodes: vec![],
+            items: Slab::new(),
+        }
+    }
+
+    /// The shape for a handle, None if it was removed
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.items.get(handle)
+    }
+
+    fn add_node(&mut self, node: Node) -> usize {
+        match self.free_nodes.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// The box around a shape in a leaf, or around a node below another node
+    fn child_bounds(&self, leaf: bool, child: usize) -> Option<Rectangle> {
+        if leaf {
+            self.items.item(child).map(extent)
+        } else {
+            self.nodes[child].bounds
+        }
+    }
+
+    /// Follow the nodes that have to grow the least to take in {area}
+    fn choose_leaf(&self, area: &Rectangle) -> usize {
+        let mut node = self.root;
+
+        while !self.nodes[node].leaf {
+            let growth = |child: &usize| {
+                let current = self.nodes[*child].bounds.map_or(0.0, |b| b.area());
+                let grown = self.nodes[*child]
+                    .bounds
+                    .map_or(area.area(), |b| union(&b, area).area());
+
+                (grown - current, current)
+            };
+
+            node = *self.nodes[node]
+                .children
+                .iter()
+                .min_by(|a, b| {
+                    growth(a)
+                        .partial_cmp(&growth(b))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(&node);
+        }
+
+        node
+    }
+
+    /// Recalculate the box of {node} from its children, and of every node above it
+    fn refit(&mut self, mut node: usize) {
+        loop {
+            let Node {
+                leaf, ref children, ..
+            } = self.nodes[node];
+            let bounds = children
+                .iter()
+                .filter_map(|child| self.child_bounds(leaf, *child))
+                .reduce(|a, b| union(&a, &b));
+            self.nodes[node].bounds = bounds;
+
+            match self.nodes[node].parent {
+                Some(parent) => node = parent,
+                None => break,
+            }
+        }
+    }
+
+    /// Split {node} in two halves along the direction its children are spread out the most,
+    /// which can make its parent too full in turn.
+    fn split_when_full(&mut self, node: usize) {
+        let Node { leaf, parent, .. } = self.nodes[node];
+        if self.nodes[node].children.len() <= MAX_CHILDREN {
+            return;
+        }
+
+        let mut children: Vec<(Point, usize)> = std::mem::take(&mut self.nodes[node].children)
+            .into_iter()
+            .map(|child| {
+                let center = self.child_bounds(leaf, child).map_or(Point(0.0, 0.0), |b| {
+                    b.position.offset(b.width / 2.0, b.height / 2.0)
+                });
+                (center, child)
+            })
+            .collect();
+
+        let spread = |axis: fn(&Point) -> f64| {
+            let values = children.iter().map(|(center, _)| axis(center));
+            values.clone().fold(f64::MIN, f64::max) - values.fold(f64::MAX, f64::min)
+        };
+        let axis: fn(&Point) -> f64 = if spread(|p| p.0) >= spread(|p| p.1) {
+            |p| p.0
+        } else {
+            |p| p.1
+        };
+        children.sort_by(|a, b| axis(&a.0).total_cmp(&axis(&b.0)));
+
+        let second: Vec<usize> = children
+            .split_off(children.len() / 2)
+            .into_iter()
+            .map(|(_, child)| child)
+            .collect();
+        self.nodes[node].children = children.into_iter().map(|(_, child)| child).collect();
+
+        let sibling = self.add_node(Node {
+            bounds: None,
+            parent,
+            leaf,
+            children: second.clone(),
+        });
+        for child in second {
+            if leaf {
+                self.items.set_location(child, sibling);
+            } else {
+                self.nodes[child].parent = Some(sibling);
+            }
+        }
+
+        let parent = match parent {
+            Some(parent) => {
+                self.nodes[parent].children.push(sibling);
+                parent
+            }
+            None => {
+                let root = self.add_node(Node {
+                    bounds: None,
+                    parent: None,
+                    leaf: false,
+                    children: vec![node, sibling],
+                });
+                self.nodes[node].parent = Some(root);
+                self.nodes[sibling].parent = Some(root);
+                self.root = root;
+                root
+            }
+        };
+
+        self.refit(node);
+        self.refit(sibling);
+        self.split_when_full(parent);
+    }
+
+    /// Drop {node} from the tree if it is empty, and its parent if that is left empty
+    fn condense(&mut self, mut node: usize) {
+        while let Some(parent) = self.nodes[node].parent {
+            if !self.nodes[node].children.is_empty() {
+                break;
+            }
+
+            self.nodes[parent].children.retain(|child| *child != node);
+            self.free_nodes.push(node);
+            node = parent;
+        }
+
+        self.refit(node);
+
+        // A root with a single node below it only adds a level
+        while !self.nodes[self.root].leaf && self.nodes[self.root].children.len() == 1 {
+            let child = self.nodes[self.root].children[0];
+            self.free_nodes.push(self.root);
+            self.nodes[child].parent = None;
+            self.root = child;
+        }
+    }
+
+    /// Every leaf, from the top down, with a box that {visit} is true for
+    fn visit(&self, visit: impl Fn(&Rectangle) -> bool) -> Vec<usize> {
+        let mut leaves = vec![];
+        let mut stack = vec![self.root];
+
+        while let Some(node) = stack.pop() {
+            let Node {
+                bounds,
+                leaf,
+                ref children,
+                ..
+            } = self.nodes[node];
+            if !bounds.is_some_and(|b| visit(&b)) {
+                continue;
+            }
+
+            if leaf {
+                leaves.push(node);
+            } else {
+                stack.extend(children);
+            }
+        }
+
+        leaves
+    }
+
+    fn leaf_items(&self, leaf: usize) -> impl Iterator<Item = &T> {
+        self.nodes[leaf]
+            .children
+            .iter()
+            .filter_map(|i| self.items.item(*i))
+    }
+
+    fn nearest_in<'b>(&'b self, node: usize, point: &Point, best: &mut Option<(f64, &'b T)>) {
+        if self.nodes[node].leaf {
+            for item in self.leaf_items(node) {
+                let distance = box_distance(&extent(item), point);
+                if best.is_none_or(|(closest, _)| distance < closest) {
+                    *best = Some((distance, item));
+                }
+            }
+            return;
+        }
+
+        let mut children: Vec<(f64, usize)> = self.nodes[node]
+            .children
+            .iter()
+            .filter_map(|child| {
+                self.nodes[*child]
+                    .bounds
+                    .map(|b| (box_distance(&b, point), *child))
+            })
+            .collect();
+        children.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        for (distance, child) in children {
+            if best.is_none_or(|(closest, _)| distance < closest) {
+                self.nearest_in(child, point, best);
+            }
+        }
+    }
+}
+
+impl<T: Shape> Default for RTree<T> {
+    fn default() -> Self {
+        RTree::new()
+    }
+}
+
+impl<T: Shape> SpatialIndex<T> for RTree<T> {
+    /// Shapes can be anywhere, so inserting always succeeds
+    fn insert(&mut self, shape: T) -> Result<Handle, T> {
+        let area = extent(&shape);
+        let leaf = self.choose_leaf(&area);
+
+        let handle = self.items.insert(shape, leaf);
+        self.nodes[leaf].children.push(handle.index);
+        self.refit(leaf);
+        self.split_when_full(leaf);
+
+        Ok(handle)
+    }
+
+    fn remove(&mut self, handle: Handle) -> Option<T> {
+        self.items.get(handle)?;
+
+        let leaf = self.items.location(handle.index);
+        self.nodes[leaf].children.retain(|i| *i != handle.index);
+        let removed = self.items.remove(handle).map(|(item, _)| item);
+        self.condense(leaf);
+
+        removed
+    }
+
+    fn query_rectangle(&self, area: &Rectangle) -> Vec<&T> {
+        self.visit(|bounds| overlaps(bounds, area))
+            .into_iter()
+            .flat_map(|leaf| self.leaf_items(leaf))
+            .filter(|item| overlaps(&extent(*item), area))
+            .collect()
+    }
+
+    fn query_radius(&self, point: &Point, radius: f64) -> Vec<&T> {
+        self.visit(|bounds| box_distance(bounds, point) <= radius)
+            .into_iter()
+            .flat_map(|leaf| self.leaf_items(leaf))
+            .filter(|item| box_distance(&extent(*item), point) <= radius)
+            .collect()
+    }
+
+    fn nearest(&self, point: &Point) -> Option<&T> {
+        let mut best = None;
+        self.nearest_in(self.root, point, &mut best);
+
+        best.map(|(_, item)| item)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{point::Point, spatial_index::SpatialIndex};
+
+    use super::RTree;
+
+    #[test]
+    fn grows_and_shrinks() {
+        let mut tree = RTree::new();
+        let handles: Vec<_> = (0..100)
+            .map(|i| tree.insert(Point(i as f64 * 10., -(i as f64))).unwrap())
+            .collect();
+
+        assert!(!tree.nodes[tree.root].leaf);
+        assert_eq!(tree.query_radius(&Point(500., -50.), 15.).len(), 3);
+
+        for handle in &handles[1..] {
+            assert!(tree.remove(*handle).is_some());
+        }
+
+        assert!(tree.nodes[tree.root].leaf);
+        assert_eq!(tree.nearest(&Point(500., 500.)), Some(&Point(0., 0.)));
+    }
+}
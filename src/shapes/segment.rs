@@ -0,0 +1,281 @@
+use std::f64::consts::{PI, TAU};
+
+use super::point::Point;
+
+/**
+Describes how a [`Path`](super::path::Path) gets from one of its points to the next.
+
+Example
+```
+use generative_art::shapes::{path::Path, point::Point, segment::Segment};
+
+let mut path = Path::new(vec![Point(0.0, 0.0)], Default::default());
+path.add_segment(Segment::Quadratic(Point(50.0, -50.0)), Point(100.0, 0.0));
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Segment {
+    /// A straight line
+    #[default]
+    Line,
+
+    /// Lift the pen and move to the next point without drawing, which starts a new
+    /// sub-path. This is how a single path holds a shape together with its holes.
+    Move,
+
+    /// A quadratic Bézier curve with a single control point
+    Quadratic(Point),
+
+    /// A cubic Bézier curve with two control points
+    Cubic(Point, Point),
+
+    /// An elliptical arc, following the parameters of the SVG `A` command
+    ///
+    /// https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/d#elliptical_arc_curve
+    Arc {
+        /// Radius along the x axis of the ellipse
+        rx: f64,
+
+        /// Radius along the y axis of the ellipse
+        ry: f64,
+
+        /// Rotation of the ellipse, in degrees
+        rotation: f64,
+
+        /// Take the longer of the two possible arcs
+        large_arc: bool,
+
+        /// Draw the arc clockwise
+        sweep: bool,
+    },
+}
+
+impl Segment {
+    /// SVG path command that draws this segment, ending at {to}.
+    pub fn as_svg(&self, to: &Point) -> String {
+        match self {
+            Segment::Line => format!(" L{:.2},{:.2}", to.0, to.1),
+            Segment::Move => format!(" M{:.2},{:.2}", to.0, to.1),
+            Segment::Quadratic(c) => format!(" Q{:.2},{:.2} {:.2},{:.2}", c.0, c.1, to.0, to.1),
+            Segment::Cubic(c1, c2) => format!(
+                " C{:.2},{:.2} {:.2},{:.2} {:.2},{:.2}",
+                c1.0, c1.1, c2.0, c2.1, to.0, to.1
+            ),
+            Segment::Arc {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+            } => format!(
+                " A{:.2},{:.2} {:.2} {} {} {:.2},{:.2}",
+                rx,
+                ry,
+                rotation,
+                u8::from(*large_arc),
+                u8::from(*sweep),
+                to.0,
+                to.1
+            ),
+        }
+    }
+
+    /**
+    Approximate this segment, going from {from} to {to}, with straight lines. Returns the
+    points after {from}, where no point on the curve is further away than {tolerance} from the
+    lines, the last point is always {to}.
+    */
+    pub fn flatten(&self, from: &Point, to: &Point, tolerance: f64) -> Vec<Point> {
+        let tolerance = tolerance.max(f64::EPSILON);
+
+        match self {
+            Segment::Line | Segment::Move => vec![*to],
+            Segment::Quadratic(c) => {
+                let c1 = from.between(c, 2.0 / 3.0);
+                let c2 = to.between(c, 2.0 / 3.0);
+                let mut points = vec![];
+                flatten_cubic(from, &c1, &c2, to, tolerance, 0, &mut points);
+                points
+            }
+            Segment::Cubic(c1, c2) => {
+                let mut points = vec![];
+                flatten_cubic(from, c1, c2, to, tolerance, 0, &mut points);
+                points
+            }
+            Segment::Arc {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+            } => flatten_arc(from, to, *rx, *ry, *rotation, *large_arc, *sweep, tolerance),
+        }
+    }
+}
+
+/// Split a cubic curve in half until each part is flat enough to be drawn as a line.
+fn flatten_cubic(
+    p0: &Point,
+    p1: &Point,
+    p2: &Point,
+    p3: &Point,
+    tolerance: f64,
+    depth: usize,
+    points: &mut Vec<Point>,
+) {
+    let flatness = distance_to_line(p1, p0, p3).max(distance_to_line(p2, p0, p3));
+
+    if flatness <= tolerance || depth > 16 {
+        points.push(*p3);
+        return;
+    }
+
+    // de Casteljau
+    let p01 = p0.between(p1, 0.5);
+    let p12 = p1.between(p2, 0.5);
+    let p23 = p2.between(p3, 0.5);
+    let p012 = p01.between(&p12, 0.5);
+    let p123 = p12.between(&p23, 0.5);
+    let middle = p012.between(&p123, 0.5);
+
+    flatten_cubic(p0, &p01, &p012, &middle, tolerance, depth + 1, points);
+    flatten_cubic(&middle, &p123, &p23, p3, tolerance, depth + 1, points);
+}
+
+/// Flatten an arc by converting it to its center parameterization, as described in
+/// https://www.w3.org/TR/SVG11/implnote.html#ArcConversionEndpointToCenter
+#[allow(clippy::too_many_arguments)]
+fn flatten_arc(
+    from: &Point,
+    to: &Point,
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    tolerance: f64,
+) -> Vec<Point> {
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 || from == to {
+        return vec![*to];
+    }
+
+    let phi = rotation.to_radians();
+    let (sin, cos) = phi.sin_cos();
+
+    let dx = (from.0 - to.0) / 2.0;
+    let dy = (from.1 - to.1) / 2.0;
+    let x1 = cos * dx + sin * dy;
+    let y1 = -sin * dx + cos * dy;
+
+    // Scale up the radii if they are too small to reach between the points
+    let lambda = x1.powi(2) / rx.powi(2) + y1.powi(2) / ry.powi(2);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx.powi(2) * ry.powi(2) - rx.powi(2) * y1.powi(2) - ry.powi(2) * x1.powi(2);
+    let denominator = rx.powi(2) * y1.powi(2) + ry.powi(2) * x1.powi(2);
+    let mut factor = (numerator / denominator).max(0.0).sqrt();
+    if large_arc == sweep {
+        factor = -factor;
+    }
+
+    let cx1 = factor * rx * y1 / ry;
+    let cy1 = -factor * ry * x1 / rx;
+    let cx = cos * cx1 - sin * cy1 + (from.0 + to.0) / 2.0;
+    let cy = sin * cx1 + cos * cy1 + (from.1 + to.1) / 2.0;
+
+    let start = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+    let end = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+    let mut delta = end - start;
+    if sweep && delta < 0.0 {
+        delta += TAU;
+    } else if !sweep && delta > 0.0 {
+        delta -= TAU;
+    }
+
+    let radius = rx.max(ry);
+    let step = 2.0 * (1.0 - (tolerance / radius).min(1.0)).acos();
+    let count = (delta.abs() / step.max(PI / 1800.0)).ceil().max(1.0) as usize;
+
+    let mut points: Vec<Point> = (1..count)
+        .map(|i| {
+            let angle = start + delta * i as f64 / count as f64;
+            let (x, y) = (rx * angle.cos(), ry * angle.sin());
+            Point(cos * x - sin * y + cx, sin * x + cos * y + cy)
+        })
+        .collect();
+    points.push(*to);
+
+    points
+}
+
+/// Distance from {point} to the infinite line through {a} and {b}.
+fn distance_to_line(point: &Point, a: &Point, b: &Point) -> f64 {
+    let length = a.distance_to(b);
+    if length == 0.0 {
+        return point.distance_to(a);
+    }
+
+    ((b.0 - a.0) * (a.1 - point.1) - (a.0 - point.0) * (b.1 - a.1)).abs() / length
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::point::Point;
+
+    use super::Segment;
+
+    #[test]
+    fn flatten_cubic_stays_close() {
+        let segment = Segment::Cubic(Point(0., 100.), Point(100., 100.));
+        let points = segment.flatten(&Point(0., 0.), &Point(100., 0.), 0.5);
+
+        assert!(points.len() > 4);
+        assert_eq!(points.last(), Some(&Point(100., 0.)));
+        // The curve peaks at 75 in the middle
+        assert!(points
+            .iter()
+            .any(|p| (p.0 - 50.).abs() < 1. && (p.1 - 75.).abs() < 1.));
+    }
+
+    #[test]
+    fn flatten_arc_half_circle() {
+        let segment = Segment::Arc {
+            rx: 50.,
+            ry: 50.,
+            rotation: 0.,
+            large_arc: false,
+            sweep: true,
+        };
+        let points = segment.flatten(&Point(0., 0.), &Point(100., 0.), 0.1);
+
+        assert!(points
+            .iter()
+            .all(|p| (p.distance_to(&Point(50., 0.)) - 50.).abs() < 1e-6));
+        assert!(points.iter().all(|p| p.1 <= 1e-6));
+    }
+
+    #[test]
+    fn svg_commands() {
+        let to = Point(10., 0.);
+
+        assert_eq!(
+            Segment::Quadratic(Point(5., 5.)).as_svg(&to),
+            " Q5.00,5.00 10.00,0.00"
+        );
+        assert_eq!(
+            Segment::Arc {
+                rx: 5.,
+                ry: 5.,
+                rotation: 0.,
+                large_arc: true,
+                sweep: false
+            }
+            .as_svg(&to),
+            " A5.00,5.00 0.00 1 0 10.00,0.00"
+        );
+    }
+}
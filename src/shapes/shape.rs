@@ -1,4 +1,6 @@
-use super::{point::Point, rectangle::Rectangle};
+use std::f64::consts::PI;
+
+use super::{path::Path, point::Point, rectangle::Rectangle};
 
 /// Generic shape definition, can be a Circle, Rectangle, Path, etc
 pub trait Shape {
@@ -19,4 +21,106 @@ pub trait Shape {
 
     /// True if the given shape contains {point}, otherwise false.
     fn contains(&self, point: &Point) -> bool;
+
+    /**
+    Signed distance from {point} to the outline of this shape, negative when the point is
+    inside the shape. Lines and other shapes without an inside never give a negative distance.
+    */
+    fn distance_to(&self, point: &Point) -> f64 {
+        let distance = self.to_polygon(0.1).distance_to_outline(point);
+
+        if self.contains(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    /// Surface area of this shape, lines and other shapes that are not closed have no area.
+    fn area(&self) -> f64 {
+        self.to_polygon(0.1).area()
+    }
+
+    /// Length of the outline of this shape, or the length of the line for shapes that are not closed.
+    fn perimeter(&self) -> f64 {
+        self.to_polygon(0.1).perimeter()
+    }
+
+    /**
+    This shape as a [`Path`] of straight lines, where no part of the outline is further away
+    than {tolerance} from the lines. Closed shapes give closed paths with the fill color of
+    the shape, so generic algorithms like hatching or clipping only have to handle paths.
+    */
+    fn to_polygon(&self, tolerance: f64) -> Path;
+
+    /// A copy of this shape, moved {x} to the right and {y} down.
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape>;
+
+    /**
+    A copy of this shape, rotated {angle} radians around {origin}. Positive angles turn
+    clockwise, since the y axis points down in SVG. Pass the [`Shape::center`] of the shape
+    as {origin} to rotate it in place.
+
+    Shapes that can't be rotated, like a [`Rectangle`] which is always aligned with the axes,
+    are turned into a [`Path`] first.
+    */
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape>;
+
+    /// A copy of this shape that is {factor} times larger, growing away from {origin}.
+    /// Stroke widths are not scaled.
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape>;
+
+    /**
+    SVG representation of the part of this shape that lies within {bounds}, used when
+    an artwork is split up over several sheets.
+
+    Shapes that can't be cut are kept whole, but only by the bounds that contain their
+    center. When an area is split up into bounds that don't overlap they are drawn exactly
+    once, with overlapping bounds they are drawn by every bounds their center falls in.
+    */
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        if bounds.contains(&self.center()) {
+            self.as_svg()
+        } else {
+            String::from("")
+        }
+    }
+}
+
+/**
+How many radians a shape described by a center, a size and a rotation turns when it is scaled
+by {factor}. Scaling by a negative factor mirrors every point through the origin of the scale,
+which for these shapes is the same as scaling by the positive factor and turning the result
+half a lap around its new center.
+*/
+pub(super) fn scale_turn(factor: f64) -> f64 {
+    if factor < 0.0 {
+        PI
+    } else {
+        0.0
+    }
+}
+
+/**
+[`Shape::as_clipped_svg`] for shapes that have an SVG element of their own: {shape} is kept as
+is when it fits within {bounds}, and only when it is partly outside is its {outline} cut along
+{bounds} and drawn as a path instead.
+*/
+pub(super) fn clip_outline(
+    shape: &dyn Shape,
+    outline: impl FnOnce() -> Path,
+    bounds: &Rectangle,
+) -> String {
+    let Some(outer) = shape.bounding_box() else {
+        return String::from("");
+    };
+
+    match outer.intersection(bounds) {
+        None => String::from(""),
+        Some(overlap) if overlap == outer => shape.as_svg(),
+        Some(_) => match outline().clip_polygon(bounds) {
+            Some(path) => path.as_svg(),
+            None => String::from(""),
+        },
+    }
 }
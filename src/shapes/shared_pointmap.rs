@@ -0,0 +1,318 @@
+use std::sync::{PoisonError, RwLock};
+
+use super::{point::Point, rectangle::Rectangle, shape::Shape};
+
+/**
+A [`PointMap`](super::pointmap::PointMap) that owns its bounds and can be shared between
+threads, e.g. with [`std::thread::scope`] or in an `Arc`. The rows of cells are split up into
+bands that each have their own lock, so threads can read from the whole map at the same time,
+and threads adding shapes only wait on each other when they add to the same band.
+
+Queries give back copies of the shapes, since no lock is held once a query returns.
+
+Example
+```
+use std::thread;
+
+use generative_art::shapes::{
+    point::Point, rectangle::Rectangle, shared_pointmap::SharedPointMap,
+};
+
+let map: SharedPointMap<Point> =
+    SharedPointMap::new(Rectangle::new(Point(0.0, 0.0), 100.0, 100.0), 10);
+
+thread::scope(|scope| {
+    for worker in 0..4 {
+        let map = &map;
+        scope.spawn(move || {
+            let points = (0..25).map(|i| Point(i as f64 * 4.0, worker as f64 * 25.0));
+            map.insert_batch(points);
+        });
+    }
+});
+
+assert_eq!(map.len(), 100);
+assert_eq!(map.nearest(&Point(51.0, 30.0)), Some(Point(52.0, 25.0)));
+```
+*/
+#[derive(Debug)]
+pub struct SharedPointMap<T: Shape> {
+    bounds: Rectangle,
+    columns: usize,
+    rows: usize,
+    cell_size: f64,
+    rows_per_shard: usize,
+
+    /// Bands of {rows_per_shard} rows of cells, row by row
+    shards: Vec<RwLock<Vec<Vec<T>>>>,
+}
+
+impl<T: Shape + Clone> SharedPointMap<T> {
+    /**
+    Create a new map for {bounds}, split into {resolution} columns and as many rows as it
+    takes to keep the cells square, see [`PointMap::new`](super::pointmap::PointMap::new).
+    There are a few bands of rows for each thread the machine can run at the same time.
+    */
+    pub fn new(bounds: Rectangle, resolution: usize) -> SharedPointMap<T> {
+        let threads = std::thread::available_parallelism().map_or(4, |n| n.get());
+        SharedPointMap::with_shards(bounds, resolution, threads * 4)
+    }
+
+    /// Create a new map like [`SharedPointMap::new`], with the rows split into {shards} bands.
+    pub fn with_shards(bounds: Rectangle, resolution: usize, shards: usize) -> SharedPointMap<T> {
+        let columns = resolution.max(1);
+        let cell_size = if bounds.width > 0.0 {
+            bounds.width / columns as f64
+        } else {
+            bounds.height.max(1.0)
+        };
+        let rows = ((bounds.height / cell_size).ceil() as usize).max(1);
+        let rows_per_shard = rows.div_ceil(shards.clamp(1, rows));
+
+        SharedPointMap {
+            bounds,
+            columns,
+            rows,
+            cell_size,
+            rows_per_shard,
+            shards: (0..rows.div_ceil(rows_per_shard))
+                .map(|_| RwLock::new(vec![vec![]; columns * rows_per_shard]))
+                .collect(),
+        }
+    }
+
+    /// Insert a new shape, or give it back if it is outside of the bounds
+    pub fn insert(&self, shape: T) -> Result<(), T> {
+        let Some((shard, cell)) = self.get_index(&shape.center()) else {
+            return Err(shape);
+        };
+
+        self.shards[shard]
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)[cell]
+            .push(shape);
+
+        Ok(())
+    }
+
+    /// Insert many shapes at once, taking the lock for each band only once. The shapes
+    /// that are outside of the bounds are given back.
+    pub fn insert_batch(&self, shapes: impl IntoIterator<Item = T>) -> Vec<T> {
+        let mut outside = vec![];
+        let mut sorted: Vec<Vec<(usize, T)>> = vec![vec![]; self.shards.len()];
+
+        for shape in shapes {
+            match self.get_index(&shape.center()) {
+                Some((shard, cell)) => sorted[shard].push((cell, shape)),
+                None => outside.push(shape),
+            }
+        }
+
+        for (shard, shapes) in sorted.into_iter().enumerate() {
+            if shapes.is_empty() {
+                continue;
+            }
+
+            let mut cells = self.shards[shard]
+                .write()
+                .unwrap_or_else(PoisonError::into_inner);
+            for (cell, shape) in shapes {
+                cells[cell].push(shape);
+            }
+        }
+
+        outside
+    }
+
+    /// All shapes in the map
+    pub fn get_items(&self) -> Vec<T> {
+        self.shards
+            .iter()
+            .flat_map(|shard| {
+                shard
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .iter()
+                    .flatten()
+                    .cloned()
+                    .collect::<Vec<T>>()
+            })
+            .collect()
+    }
+
+    /// How many shapes are in the map
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| {
+                shard
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .iter()
+                    .map(Vec::len)
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+
+    /// True if there are no shapes in the map
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// All shapes with their center within {radius} of {point}, the point itself can be
+    /// outside of the bounds.
+    pub fn within_radius(&self, point: &Point, radius: f64) -> Vec<T> {
+        let radius = radius.max(0.0);
+        let (first_column, first_row) = self.cell_position(&point.offset(-radius, -radius));
+        let (last_column, last_row) = self.cell_position(&point.offset(radius, radius));
+
+        if last_column < 0
+            || last_row < 0
+            || first_column >= self.columns as isize
+            || first_row >= self.rows as isize
+        {
+            return vec![];
+        }
+
+        let columns = first_column.max(0) as usize..=(last_column as usize).min(self.columns - 1);
+        let rows = first_row.max(0) as usize..=(last_row as usize).min(self.rows - 1);
+
+        let mut found = vec![];
+        let shards = rows.start() / self.rows_per_shard..=rows.end() / self.rows_per_shard;
+
+        for shard in shards {
+            let cells = self.shards[shard]
+                .read()
+                .unwrap_or_else(PoisonError::into_inner);
+            let shard_rows = shard * self.rows_per_shard..(shard + 1) * self.rows_per_shard;
+
+            for row in shard_rows.filter(|row| rows.contains(row)) {
+                let offset = (row % self.rows_per_shard) * self.columns;
+
+                found.extend(
+                    columns
+                        .clone()
+                        .flat_map(|column| cells[offset + column].iter())
+                        .filter(|shape| shape.center().distance_to(point) <= radius)
+                        .cloned(),
+                );
+            }
+        }
+
+        found
+    }
+
+    /// The shape with its center closest to {point}, None if the map is empty. The search
+    /// starts close by, and looks twice as far each time nothing is found.
+    pub fn nearest(&self, point: &Point) -> Option<T> {
+        let corner = self
+            .bounds
+            .position
+            .offset(self.bounds.width, self.bounds.height);
+        let furthest = point
+            .distance_to(&self.bounds.position)
+            .max(point.distance_to(&corner))
+            + self.bounds.width
+            + self.bounds.height;
+
+        let mut radius = self.cell_size;
+        loop {
+            let found = self.within_radius(point, radius);
+            if !found.is_empty() {
+                return found.into_iter().min_by(|a, b| {
+                    a.center()
+                        .distance_to(point)
+                        .total_cmp(&b.center().distance_to(point))
+                });
+            }
+            if radius > furthest {
+                return None;
+            }
+
+            radius *= 2.0;
+        }
+    }
+
+    /// The column and row {point} would be in if the grid went on forever
+    fn cell_position(&self, point: &Point) -> (isize, isize) {
+        (
+            ((point.0 - self.bounds.position.0) / self.cell_size).floor() as isize,
+            ((point.1 - self.bounds.position.1) / self.cell_size).floor() as isize,
+        )
+    }
+
+    /// The band and the cell within it that {point} is in, None if it is outside the bounds
+    fn get_index(&self, point: &Point) -> Option<(usize, usize)> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        // Rounding can put points close to the far edges past the last row or column
+        let (column, row) = self.cell_position(point);
+        let column = (column.max(0) as usize).min(self.columns - 1);
+        let row = (row.max(0) as usize).min(self.rows - 1);
+
+        Some((
+            row / self.rows_per_shard,
+            (row % self.rows_per_shard) * self.columns + column,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, thread};
+
+    use crate::shapes::{circle::Circle, point::Point, rectangle::Rectangle};
+
+    use super::SharedPointMap;
+
+    #[test]
+    fn parallel_inserts_and_reads() {
+        let bounds = Rectangle::new(Point(-50., 0.), 200., 300.);
+        let map: Arc<SharedPointMap<Circle>> = Arc::new(SharedPointMap::with_shards(bounds, 20, 7));
+
+        let workers: Vec<_> = (0..8)
+            .map(|worker| {
+                let map = Arc::clone(&map);
+                thread::spawn(move || {
+                    let circles = (0..100)
+                        .map(|i| Circle::new(Point(-50. + i as f64 * 2., worker as f64 * 40.), 1.));
+                    let outside = map.insert_batch(circles);
+
+                    // Reading while the other threads are still writing
+                    assert!(!map
+                        .within_radius(&Point(0., worker as f64 * 40.), 5.)
+                        .is_empty());
+                    outside.len()
+                })
+            })
+            .collect();
+
+        let outside: usize = workers.into_iter().map(|w| w.join().unwrap()).sum();
+        assert_eq!(outside, 0);
+        assert_eq!(map.len(), 800);
+
+        let close = map.within_radius(&Point(10., 120.), 4.5);
+        assert_eq!(close.len(), 5);
+        assert!(close.iter().all(|c| c.center.1 == 120.));
+
+        assert!(map.insert(Circle::new(Point(150., 10.), 1.)).is_err());
+        assert_eq!(
+            map.nearest(&Point(1000., 1000.)).map(|c| c.center),
+            Some(Point(148., 280.))
+        );
+        assert_eq!(map.get_items().len(), 800);
+    }
+
+    #[test]
+    fn empty_map() {
+        let map: SharedPointMap<Point> =
+            SharedPointMap::new(Rectangle::new(Point(0., 0.), 10., 10.), 3);
+
+        assert!(map.is_empty());
+        assert_eq!(map.nearest(&Point(5., 5.)), None);
+        assert!(map.within_radius(&Point(-100., -100.), 10.).is_empty());
+    }
+}
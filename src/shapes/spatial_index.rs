@@ -0,0 +1,313 @@
+use super::{point::Point, rectangle::Rectangle, shape::Shape};
+
+/**
+A structure that keeps track of where shapes are, so the shapes in some part of the plane can
+be found without looking at every one of them. Each implementation suits a different kind of
+drawing:
+
+- [`PointMap`](super::pointmap::PointMap), a grid of equally sized cells, is the fastest for
+  many small shapes that are spread out evenly.
+- [`QuadTree`](super::quadtree::QuadTree) splits up the plane where shapes crowd together,
+  so it handles shapes of very different sizes and uneven densities.
+- [`RTree`](super::rtree::RTree) groups shapes that are close together into boxes, which
+  works well for large or long shapes that don't fit nicely into a grid or quadrant.
+
+All of them look at the [`Shape::bounding_box`] of the shapes, so queries find shapes that
+reach into an area, not only the shapes with their center in it.
+
+Example
+```
+use generative_art::shapes::{
+    circle::Circle, point::Point, quadtree::QuadTree, rectangle::Rectangle,
+    spatial_index::SpatialIndex,
+};
+
+fn crowded(index: &impl SpatialIndex<Circle>, circle: &Circle) -> bool {
+    index
+        .query_radius(&circle.center, circle.radius)
+        .iter()
+        .any(|other| other.intersects(circle))
+}
+
+let bounds = Rectangle::new(Point(0.0, 0.0), 1000.0, 1000.0);
+let mut index = QuadTree::new(bounds);
+let _ = index.insert(Circle::new(Point(500.0, 500.0), 250.0));
+
+assert!(crowded(&index, &Circle::new(Point(800.0, 500.0), 100.0)));
+assert!(!crowded(&index, &Circle::new(Point(900.0, 900.0), 40.0)));
+```
+*/
+pub trait SpatialIndex<T: Shape> {
+    /// Add {shape}, giving back a handle to remove it later, or the shape itself if it
+    /// can't be stored, e.g. when it is outside of the bounds.
+    fn insert(&mut self, shape: T) -> Result<Handle, T>;
+
+    /// Remove a shape, giving it back unless it was already removed.
+    fn remove(&mut self, handle: Handle) -> Option<T>;
+
+    /// All shapes with a bounding box that overlaps {area}.
+    fn query_rectangle(&self, area: &Rectangle) -> Vec<&T>;
+
+    /// All shapes with a bounding box that is at most {radius} away from {point}.
+    fn query_radius(&self, point: &Point, radius: f64) -> Vec<&T>;
+
+    /// The shape with the bounding box closest to {point}, None if there are no shapes.
+    fn nearest(&self, point: &Point) -> Option<&T>;
+}
+
+/**
+A reference to a shape in a [`SpatialIndex`], returned when the shape is inserted. It stays
+valid until the shape is removed, also when the shape is moved around in the index.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    pub(crate) index: usize,
+
+    /// Tells apart shapes that were stored in the same entry at different times
+    generation: u32,
+}
+
+/**
+The shapes of a spatial index, in entries that are reused once their shape is removed. Each
+entry remembers a {location}, like a cell or a node, so the shape can be found in the index
+without searching for it.
+*/
+#[derive(Debug, Clone)]
+pub(crate) struct Slab<T, L> {
+    entries: Vec<Entry<T, L>>,
+
+    /// Entries that are free to be reused
+    free: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<T, L> {
+    item: Option<T>,
+    generation: u32,
+    location: L,
+}
+
+impl<T, L: Copy> Slab<T, L> {
+    pub(crate) fn new() -> Slab<T, L> {
+        Slab {
+            entries: vec![],
+            free: vec![],
+        }
+    }
+
+    pub(crate) fn insert(&mut self, item: T, location: L) -> Handle {
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.entries[index].item = Some(item);
+                self.entries[index].location = location;
+                index
+            }
+            None => {
+                self.entries.push(Entry {
+                    item: Some(item),
+                    generation: 0,
+                    location,
+                });
+                self.entries.len() - 1
+            }
+        };
+
+        self.handle(index)
+    }
+
+    /// Take the shape out, with the location it was at
+    pub(crate) fn remove(&mut self, handle: Handle) -> Option<(T, L)> {
+        self.get(handle)?;
+
+        let entry = &mut self.entries[handle.index];
+        entry.generation = entry.generation.wrapping_add(1);
+        self.free.push(handle.index);
+
+        entry.item.take().map(|item| (item, entry.location))
+    }
+
+    /// The shape for a handle, None if it was removed
+    pub(crate) fn get(&self, handle: Handle) -> Option<&T> {
+        self.entries
+            .get(handle.index)
+            .filter(|entry| entry.generation == handle.generation)
+            .and_then(|entry| entry.item.as_ref())
+    }
+
+    /// The shape in the entry at {index}, None if it was removed
+    pub(crate) fn item(&self, index: usize) -> Option<&T> {
+        self.entries[index].item.as_ref()
+    }
+
+    pub(crate) fn replace(&mut self, index: usize, item: T) {
+        self.entries[index].item = Some(item);
+    }
+
+    pub(crate) fn location(&self, index: usize) -> L {
+        self.entries[index].location
+    }
+
+    pub(crate) fn set_location(&mut self, index: usize, location: L) {
+        self.entries[index].location = location;
+    }
+
+    /// A handle to the current shape in the entry at {index}
+    pub(crate) fn handle(&self, index: usize) -> Handle {
+        Handle {
+            index,
+            generation: self.entries[index].generation,
+        }
+    }
+}
+
+/// The bounding box of {shape}, or an empty box at its center for shapes without one
+pub(crate) fn extent<T: Shape>(shape: &T) -> Rectangle {
+    shape
+        .bounding_box()
+        .unwrap_or_else(|| Rectangle::new(shape.center(), 0.0, 0.0))
+}
+
+/// True if the boxes overlap or touch
+pub(crate) fn overlaps(a: &Rectangle, b: &Rectangle) -> bool {
+    a.position.0 <= b.position.0 + b.width
+        && b.position.0 <= a.position.0 + a.width
+        && a.position.1 <= b.position.1 + b.height
+        && b.position.1 <= a.position.1 + a.height
+}
+
+/// True if {inner} lies completely within {outer}
+pub(crate) fn encloses(outer: &Rectangle, inner: &Rectangle) -> bool {
+    outer.position.0 <= inner.position.0
+        && outer.position.1 <= inner.position.1
+        && inner.position.0 + inner.width <= outer.position.0 + outer.width
+        && inner.position.1 + inner.height <= outer.position.1 + outer.height
+}
+
+/// How far {point} is from the box, 0 if it is inside
+pub(crate) fn box_distance(rect: &Rectangle, point: &Point) -> f64 {
+    let dx = (rect.position.0 - point.0)
+        .max(point.0 - rect.position.0 - rect.width)
+        .max(0.0);
+    let dy = (rect.position.1 - point.1)
+        .max(point.1 - rect.position.1 - rect.height)
+        .max(0.0);
+
+    dx.hypot(dy)
+}
+
+/// The smallest box around both {a} and {b}
+pub(crate) fn union(a: &Rectangle, b: &Rectangle) -> Rectangle {
+    let x1 = a.position.0.min(b.position.0);
+    let y1 = a.position.1.min(b.position.1);
+    let x2 = (a.position.0 + a.width).max(b.position.0 + b.width);
+    let y2 = (a.position.1 + a.height).max(b.position.1 + b.height);
+
+    Rectangle::new(Point(x1, y1), x2 - x1, y2 - y1)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{
+        circle::Circle, point::Point, pointmap::PointMap, quadtree::QuadTree, rectangle::Rectangle,
+        rtree::RTree,
+    };
+
+    use super::{box_distance, SpatialIndex};
+
+    /// A grid of circles of growing size, with every other circle removed again
+    fn fill(index: &mut impl SpatialIndex<Circle>) -> Vec<Circle> {
+        let mut kept = vec![];
+
+        for i in 0..400 {
+            let circle = Circle::new(
+                Point((i % 20) as f64 * 50. + 25., (i / 20) as f64 * 50. + 25.),
+                1. + (i % 7) as f64 * 15.,
+            );
+            let handle = index.insert(circle).unwrap();
+
+            if i % 2 == 0 {
+                assert_eq!(index.remove(handle), Some(circle));
+                assert_eq!(index.remove(handle), None);
+            } else {
+                kept.push(circle);
+            }
+        }
+
+        kept
+    }
+
+    fn sorted(mut circles: Vec<Circle>) -> Vec<(i64, i64, i64)> {
+        circles.sort_by(|a, b| {
+            (a.center.0, a.center.1)
+                .partial_cmp(&(b.center.0, b.center.1))
+                .unwrap()
+        });
+        circles
+            .iter()
+            .map(|c| (c.center.0 as i64, c.center.1 as i64, c.radius as i64))
+            .collect()
+    }
+
+    /// Compare the queries of {index} with checking every circle
+    fn matches_brute_force(index: &mut impl SpatialIndex<Circle>) {
+        let circles = fill(index);
+        let area = Rectangle::new(Point(220., 130.), 300., 410.);
+
+        for point in [Point(0., 0.), Point(512., 377.), Point(1200., 640.)] {
+            assert_eq!(
+                sorted(
+                    index
+                        .query_radius(&point, 120.)
+                        .into_iter()
+                        .copied()
+                        .collect()
+                ),
+                sorted(
+                    circles
+                        .iter()
+                        .filter(|c| box_distance(&super::extent(*c), &point) <= 120.)
+                        .copied()
+                        .collect()
+                )
+            );
+
+            let closest = circles
+                .iter()
+                .map(|c| box_distance(&super::extent(c), &point))
+                .fold(f64::MAX, f64::min);
+            let nearest = index.nearest(&point).unwrap();
+            assert_eq!(box_distance(&super::extent(nearest), &point), closest);
+        }
+
+        assert_eq!(
+            sorted(index.query_rectangle(&area).into_iter().copied().collect()),
+            sorted(
+                circles
+                    .iter()
+                    .filter(|c| super::overlaps(&super::extent(*c), &area))
+                    .copied()
+                    .collect()
+            )
+        );
+    }
+
+    #[test]
+    fn point_map() {
+        let bounds = Rectangle::new(Point(0., 0.), 1000., 1000.);
+        matches_brute_force(&mut PointMap::new(&bounds, 20));
+    }
+
+    #[test]
+    fn quadtree() {
+        matches_brute_force(&mut QuadTree::new(Rectangle::new(
+            Point(0., 0.),
+            1000.,
+            1000.,
+        )));
+    }
+
+    #[test]
+    fn rtree() {
+        matches_brute_force(&mut RTree::new());
+    }
+}
@@ -0,0 +1,191 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::palette::color::Color;
+
+use super::{
+    path::{Path, PathStyle},
+    point::Point,
+    rectangle::Rectangle,
+    regular_polygon::fan_contains,
+    shape::{scale_turn, Shape},
+};
+
+/// A star, with tips at one distance from the center and the dips between them at another
+#[derive(Debug, Clone, Copy)]
+pub struct Star {
+    /// Center of the star
+    pub center: Point,
+
+    /// Number of tips, at least 2
+    pub points: usize,
+
+    /// Distance from the center to each tip
+    pub outer_radius: f64,
+
+    /// Distance from the center to the dips between the tips
+    pub inner_radius: f64,
+
+    /// Rotation around the center in radians, the first tip points straight up when this is 0
+    pub rotation: f64,
+
+    /// Fill color for the star
+    color: Option<Color>,
+}
+
+impl Star {
+    /// Create a new star around {center} with the given number of {points}.
+    ///
+    /// ```
+    /// use generative_art::shapes::{point::Point, star::Star};
+    /// let star = Star::new(Point(0.0, 0.0), 5, 20.0, 8.0);
+    /// ```
+    pub fn new(center: Point, points: usize, outer_radius: f64, inner_radius: f64) -> Star {
+        Star {
+            center,
+            points: points.max(2),
+            outer_radius,
+            inner_radius,
+            rotation: 0.0,
+            color: None,
+        }
+    }
+
+    /// Set the fill color of the star
+    pub fn set_color(&mut self, color: Color) {
+        self.color = Some(color);
+    }
+
+    /// The corners of the star going clockwise from the top, alternating between tips and dips
+    pub fn vertices(&self) -> Vec<Point> {
+        let count = self.points.max(2) * 2;
+
+        (0..count)
+            .map(|i| {
+                let angle = self.rotation - FRAC_PI_2 + i as f64 * PI / (count / 2) as f64;
+                let radius = if i % 2 == 0 {
+                    self.outer_radius
+                } else {
+                    self.inner_radius
+                };
+
+                Point(
+                    self.center.0 + angle.cos() * radius,
+                    self.center.1 + angle.sin() * radius,
+                )
+            })
+            .collect()
+    }
+
+    /// Converts this star to a closed [`Path`]
+    pub fn to_path(&self, style: PathStyle) -> Path {
+        let mut points = self.vertices();
+        points.push(points[0]);
+
+        Path::new(points, style)
+    }
+}
+
+impl Shape for Star {
+    fn as_svg(&self) -> String {
+        self.to_path(PathStyle {
+            color: self.color,
+            ..Default::default()
+        })
+        .as_svg()
+    }
+
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        self.to_path(PathStyle {
+            color: self.color,
+            ..Default::default()
+        })
+        .as_clipped_svg(bounds)
+    }
+
+    fn center(&self) -> Point {
+        self.center
+    }
+
+    fn bounding_box(&self) -> Option<Rectangle> {
+        self.to_path(Default::default()).bounding_box()
+    }
+
+    fn contains(&self, point: &Point) -> bool {
+        fan_contains(&self.center, &self.vertices(), point)
+    }
+
+    fn area(&self) -> f64 {
+        let points = self.points.max(2) as f64;
+        points * self.outer_radius * self.inner_radius * (PI / points).sin()
+    }
+
+    fn perimeter(&self) -> f64 {
+        let points = self.points.max(2) as f64;
+        let edge = (self.outer_radius.powi(2) + self.inner_radius.powi(2)
+            - 2.0 * self.outer_radius * self.inner_radius * (PI / points).cos())
+        .sqrt();
+
+        edge * points * 2.0
+    }
+
+    fn to_polygon(&self, _tolerance: f64) -> Path {
+        self.to_path(PathStyle {
+            color: self.color,
+            ..Default::default()
+        })
+    }
+
+    fn translate(&self, x: f64, y: f64) -> Box<dyn Shape> {
+        Box::new(Star {
+            center: self.center.offset(x, y),
+            ..*self
+        })
+    }
+
+    fn rotate(&self, angle: f64, origin: &Point) -> Box<dyn Shape> {
+        Box::new(Star {
+            center: self.center.rotate(angle, origin),
+            rotation: self.rotation + angle,
+            ..*self
+        })
+    }
+
+    fn scale(&self, factor: f64, origin: &Point) -> Box<dyn Shape> {
+        let turn = scale_turn(factor);
+
+        Box::new(Star {
+            center: self.center.scale(factor, origin),
+            outer_radius: self.outer_radius * factor.abs(),
+            inner_radius: self.inner_radius * factor.abs(),
+            rotation: self.rotation + turn,
+            ..*self
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shapes::{point::Point, shape::Shape};
+
+    use super::Star;
+
+    #[test]
+    fn contains_tips_but_not_dips() {
+        let star = Star::new(Point(0., 0.), 4, 10., 2.);
+
+        assert_eq!(star.vertices().len(), 8);
+        assert!(star.contains(&Point(0., -9.)));
+        assert!(star.contains(&Point(9., 0.)));
+        // Between two tips, outside the dip
+        assert!(!star.contains(&Point(4., -4.)));
+    }
+
+    #[test]
+    fn area_and_perimeter_match_outline() {
+        let star = Star::new(Point(0., 0.), 5, 20., 8.);
+        let outline = star.to_polygon(0.1);
+
+        assert!((star.area() - outline.area()).abs() < 1e-9);
+        assert!((star.perimeter() - outline.perimeter()).abs() < 1e-9);
+    }
+}
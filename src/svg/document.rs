@@ -1,4 +1,4 @@
-use std::{fs::File, io::Write};
+use std::{fmt::Debug, fs::File, io::Write};
 
 use chrono::{Datelike, Utc};
 
@@ -8,7 +8,6 @@ use super::group::Group;
 
 /// The SVG struct contains all the SVG information for a generated artwork.
 /// It is the common interface for all shapes when they are finally rendered.
-#[derive(Debug)]
 pub struct Document<'a> {
     /// Name of the generated art piece, will be used to control both
     /// output folder and file name.
@@ -17,8 +16,42 @@ pub struct Document<'a> {
     /// The size of the svg element
     pub bounds: Rectangle,
 
-    /// Internal storage for all the shapes added to this SVG document.
-    document: String,
+    /// Internal storage for all the shapes and groups added to this SVG document.
+    elements: Vec<Element>,
+}
+
+/// Something that has been added to a [`Document`]
+enum Element {
+    Shape(Box<dyn Shape>),
+    Group(Group),
+
+    /// SVG that has already been generated, e.g. the clipped shapes of another document.
+    /// It can't be cut any more, so it is kept whole when the document is clipped.
+    Svg(String),
+}
+
+impl Element {
+    fn as_svg(&self) -> String {
+        match self {
+            Element::Shape(shape) => shape.as_svg(),
+            Element::Group(group) => group.as_svg(),
+            Element::Svg(svg) => svg.clone(),
+        }
+    }
+
+    fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        match self {
+            Element::Shape(shape) => shape.as_clipped_svg(bounds),
+            Element::Group(group) => group.as_clipped_svg(bounds),
+            Element::Svg(svg) => svg.clone(),
+        }
+    }
+}
+
+impl Debug for Document<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Document {} {}", self.name, self.bounds)
+    }
 }
 
 impl<'a> Document<'static> {
@@ -45,34 +78,28 @@ impl<'a> Document<'static> {
     svg.save();
     ```
     */
-
     pub fn new(name: &'static str, bounds: Rectangle) -> Document<'a> {
         Document {
             name,
             bounds,
-            document: format!(
-                "<svg viewBox=\"0 0 {} {}\" xmlns=\"http://www.w3.org/2000/svg\">",
-                bounds.width, bounds.height
-            ),
+            elements: vec![],
         }
     }
 
     /// Add a shape to the SVG document
     pub fn add_shape(&mut self, shape: Box<dyn Shape>) {
-        self.document.push_str(&shape.as_svg());
+        self.elements.push(Element::Shape(shape));
     }
 
     /// Add a group to the document. Groups can hold other shapes but share styles between the shapes.
     /// Nice to have to keep the final svg file size down if there are a lot of shared style between shapes.
     pub fn add_group(&mut self, group: Group) {
-        self.document.push_str(&group.as_svg());
+        self.elements.push(Element::Group(group));
     }
 
     /// Save the SVG document to disk. Optionally a configuration string can be passed, which will be appended
     /// as an SVG comment in the file, to be able to recreate a given painting.
     pub fn save(&mut self) {
-        self.document.push_str("</svg>");
-
         let now = Utc::now();
         let time = format!(
             "{}-{}-{}-{}",
@@ -89,16 +116,49 @@ impl<'a> Document<'static> {
             time
         );
 
-        let _result = std::fs::create_dir(format!("./output/{}", self.name.to_lowercase()));
+        let _result = std::fs::create_dir_all(format!("./output/{}", self.name.to_lowercase()));
 
         let mut f = File::create(&path).expect("could not open file for writing");
 
-        f.write_all(self.document.as_bytes())
+        f.write_all(self.generate().as_bytes())
             .expect("Could not write to file");
     }
 
     /// Generate the svg in memory
     pub fn generate(&self) -> String {
-        format!("{}</svg>", self.document)
+        let content: String = self.elements.iter().map(Element::as_svg).collect();
+
+        Document::wrap(&self.bounds, content)
+    }
+
+    /// Generate an svg in memory that only shows the part of the document inside {bounds}.
+    /// Shapes are cut at the edges of the bounds, see [`Shape::as_clipped_svg`].
+    pub fn generate_clipped(&self, bounds: &Rectangle) -> String {
+        self.clip(bounds).generate()
+    }
+
+    /// A new document the size of {bounds}, with the part of each shape and group of this
+    /// document that lies within {bounds}. More shapes can be added on top of it.
+    ///
+    /// The shapes of the new document are turned into SVG, which can't be cut again, so
+    /// clipping a clipped document keeps what it got from this one whole. Clip the original
+    /// document instead, e.g. to tile it a second time.
+    pub fn clip(&self, bounds: &Rectangle) -> Document<'static> {
+        Document {
+            name: self.name,
+            bounds: *bounds,
+            elements: self
+                .elements
+                .iter()
+                .map(|element| Element::Svg(element.as_clipped_svg(bounds)))
+                .collect(),
+        }
+    }
+
+    fn wrap(bounds: &Rectangle, content: String) -> String {
+        format!(
+            "<svg viewBox=\"{} {} {} {}\" xmlns=\"http://www.w3.org/2000/svg\">{}</svg>",
+            bounds.position.0, bounds.position.1, bounds.width, bounds.height, content
+        )
     }
 }
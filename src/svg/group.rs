@@ -1,6 +1,9 @@
 use std::fmt::{Debug, Display};
 
-use crate::{palette::color::Color, shapes::shape::Shape};
+use crate::{
+    palette::color::Color,
+    shapes::{rectangle::Rectangle, shape::Shape},
+};
 
 /// A group style defined the fill, stroke width and stroke color
 /// for all shapes contained in the group, unless the styles are
@@ -65,7 +68,8 @@ g.add_shape(Box::new(rect2));
 */
 #[derive(Default)]
 pub struct Group {
-    svg: String,
+    style: Option<GroupStyle>,
+    shapes: Vec<Box<dyn Shape>>,
 }
 
 impl Debug for Group {
@@ -102,22 +106,38 @@ impl Group {
     ```
     */
     pub fn new(style: Option<GroupStyle>) -> Group {
-        let svg = match style {
-            None => String::from("<g>"),
-            Some(style) => format!("<g{style}>"),
-        };
-
-        Group { svg }
+        Group {
+            style,
+            shapes: vec![],
+        }
     }
 
     /// Add a new shape to the group
     pub fn add_shape(&mut self, shape: Box<dyn Shape>) {
-        self.svg = format!("{}{}", self.svg, shape.as_svg());
+        self.shapes.push(shape);
     }
 
     /// Get the entire SVG string of the group
     pub fn as_svg(&self) -> String {
-        format!("{}</g>", self.svg)
+        self.wrap(self.shapes.iter().map(|shape| shape.as_svg()).collect())
+    }
+
+    /// Get the SVG string of the group with only the parts of each shape
+    /// that lie within {bounds}, see [`Shape::as_clipped_svg`].
+    pub fn as_clipped_svg(&self, bounds: &Rectangle) -> String {
+        self.wrap(
+            self.shapes
+                .iter()
+                .map(|shape| shape.as_clipped_svg(bounds))
+                .collect(),
+        )
+    }
+
+    fn wrap(&self, content: String) -> String {
+        match self.style {
+            None => format!("<g>{content}</g>"),
+            Some(style) => format!("<g{style}>{content}</g>"),
+        }
     }
 }
 
@@ -135,7 +155,7 @@ mod test {
     fn render() {
         let rect = Rectangle::new(Point(0., 0.), 10.0, 10.0);
         let mut g = Group::new(Some(super::GroupStyle {
-            fill: Some(Color::Hex("#111")),
+            fill: Some(Color::hex("#111")),
             stroke: None,
             stroke_width: None,
         }));
@@ -3,3 +3,6 @@ pub mod group;
 
 /// SVG implementation
 pub mod document;
+
+/// Split a document up over several sheets
+pub mod tiling;
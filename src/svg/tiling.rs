@@ -0,0 +1,349 @@
+use std::{fs::File, io::Write};
+
+use chrono::Utc;
+
+use crate::{
+    palette::color::Color,
+    shapes::{
+        path::{Path, PathStyle},
+        point::Point,
+        rectangle::Rectangle,
+    },
+};
+
+use super::document::Document;
+
+/**
+Splits a [`Document`] up into a grid of smaller documents, one per sheet, so that
+artworks can be plotted larger than the plotter itself allows. Shapes are cut at the
+edges of each tile.
+
+Example
+```
+use generative_art::{
+    shapes::{point::Point, rectangle::Rectangle, circle::Circle},
+    svg::{document::Document, tiling::Tiling},
+};
+
+let bounds = Rectangle::new(Point(0.0, 0.0), 1000.0, 1400.0);
+let mut svg = Document::new("my-art", bounds);
+svg.add_shape(Box::new(Circle::new(Point(500.0, 700.0), 200.0)));
+
+let tiling = Tiling::for_sheet(&bounds, 420.0, 297.0, 10.0).unwrap();
+let tiles = tiling.tiles(&svg);
+
+assert_eq!(tiles.len(), tiling.columns * tiling.rows);
+```
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct Tiling {
+    /// Number of sheets along the width of the document
+    pub columns: usize,
+
+    /// Number of sheets along the height of the document
+    pub rows: usize,
+
+    /// How far each sheet reaches into its neighbors, which makes it easier
+    /// to line the sheets up and hides small gaps between them.
+    pub overlap: f64,
+
+    /// Size of the registration marks drawn at the corners of each tile,
+    /// no marks are drawn if this is None.
+    pub registration_marks: Option<f64>,
+}
+
+/// A single sheet of a [`Tiling`]
+#[derive(Debug)]
+pub struct Tile {
+    /// The row of this tile, counted from the top
+    pub row: usize,
+
+    /// The column of this tile, counted from the left
+    pub column: usize,
+
+    /// The area of the document that this tile covers, overlap included
+    pub bounds: Rectangle,
+
+    /// The SVG for this tile
+    pub svg: String,
+}
+
+impl Tiling {
+    /// Create a new tiling with the given number of columns and rows, without
+    /// overlap or registration marks.
+    pub fn new(columns: usize, rows: usize) -> Tiling {
+        Tiling {
+            columns: columns.max(1),
+            rows: rows.max(1),
+            overlap: 0.0,
+            registration_marks: None,
+        }
+    }
+
+    /// Create a tiling with as few sheets of the given size as possible to cover {bounds},
+    /// with {overlap} between each sheet. None if the overlap on both sides of a sheet
+    /// takes up the whole sheet, since no number of sheets would cover the bounds then.
+    pub fn for_sheet(
+        bounds: &Rectangle,
+        sheet_width: f64,
+        sheet_height: f64,
+        overlap: f64,
+    ) -> Option<Tiling> {
+        let overlap = overlap.max(0.0);
+        let (width, height) = (sheet_width - overlap * 2.0, sheet_height - overlap * 2.0);
+        if width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+
+        let columns = (bounds.width / width).ceil() as usize;
+        let rows = (bounds.height / height).ceil() as usize;
+
+        Some(Tiling {
+            overlap,
+            ..Tiling::new(columns, rows)
+        })
+    }
+
+    /// The area of {bounds} that the tile at the given row and column is responsible for,
+    /// without any overlap.
+    fn cell(&self, bounds: &Rectangle, row: usize, column: usize) -> Rectangle {
+        let width = bounds.width / self.columns as f64;
+        let height = bounds.height / self.rows as f64;
+
+        Rectangle::new(
+            Point(
+                bounds.position.0 + column as f64 * width,
+                bounds.position.1 + row as f64 * height,
+            ),
+            width,
+            height,
+        )
+    }
+
+    /// Split the document up into tiles, row by row.
+    pub fn tiles(&self, document: &Document<'static>) -> Vec<Tile> {
+        let mut tiles = vec![];
+
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let cell = self.cell(&document.bounds, row, column);
+                let tile_bounds = Rectangle::new(
+                    cell.position.offset(-self.overlap, -self.overlap),
+                    cell.width + self.overlap * 2.0,
+                    cell.height + self.overlap * 2.0,
+                )
+                .intersection(&document.bounds)
+                .unwrap_or(cell);
+
+                let mut tile = document.clip(&tile_bounds);
+                if let Some(size) = self.registration_marks {
+                    for mark in registration_marks(&cell, size) {
+                        tile.add_shape(Box::new(mark));
+                    }
+                }
+
+                tiles.push(Tile {
+                    row,
+                    column,
+                    bounds: tile_bounds,
+                    svg: tile.generate(),
+                });
+            }
+        }
+
+        tiles
+    }
+
+    /// A JSON description of how the tiles fit together, with the file name of each tile
+    /// as written by [`Tiling::save`].
+    pub fn manifest(&self, document: &Document<'static>, tiles: &[Tile]) -> String {
+        let tiles = tiles
+            .iter()
+            .map(|tile| {
+                format!(
+                    r#"{{"file":{},"row":{},"column":{},"x":{},"y":{},"width":{},"height":{}}}"#,
+                    json_string(&tile.file_name()),
+                    tile.row,
+                    tile.column,
+                    tile.bounds.position.0,
+                    tile.bounds.position.1,
+                    tile.bounds.width,
+                    tile.bounds.height
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+
+        format!(
+            r#"{{"name":{},"width":{},"height":{},"columns":{},"rows":{},"overlap":{},"tiles":[{}]}}"#,
+            json_string(document.name),
+            document.bounds.width,
+            document.bounds.height,
+            self.columns,
+            self.rows,
+            self.overlap,
+            tiles
+        )
+    }
+
+    /// Save each tile of the document to disk, together with a `manifest.json`
+    /// describing how to put them back together.
+    pub fn save(&self, document: &Document<'static>) {
+        let folder = format!(
+            "./output/{}/{}-tiles-{}",
+            document.name.to_lowercase(),
+            document.name,
+            Utc::now().timestamp_millis()
+        );
+
+        std::fs::create_dir_all(&folder).expect("could not create folder for tiles");
+
+        let tiles = self.tiles(document);
+
+        for tile in &tiles {
+            let mut f = File::create(format!("{folder}/{}", tile.file_name()))
+                .expect("could not open file for writing");
+            f.write_all(tile.svg.as_bytes())
+                .expect("Could not write to file");
+        }
+
+        let mut f = File::create(format!("{folder}/manifest.json"))
+            .expect("could not open file for writing");
+        f.write_all(self.manifest(document, &tiles).as_bytes())
+            .expect("Could not write to file");
+    }
+}
+
+impl Tile {
+    /// File name used for this tile when saved
+    pub fn file_name(&self) -> String {
+        format!("tile-{}-{}.svg", self.row, self.column)
+    }
+}
+
+/// {text} as a JSON string, in quotes and with quotes, backslashes and control characters escaped
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+/// A cross at each corner of {cell}, sheets are lined up by placing the marks on top of each other.
+fn registration_marks(cell: &Rectangle, size: f64) -> Vec<Path> {
+    let (x1, y1) = (cell.position.0, cell.position.1);
+    let (x2, y2) = (x1 + cell.width, y1 + cell.height);
+    let style = PathStyle {
+        stroke_weight: Some(1.0),
+        stroke: Some(Color::hex("#000")),
+        color: None,
+        ..Default::default()
+    };
+
+    [Point(x1, y1), Point(x2, y1), Point(x2, y2), Point(x1, y2)]
+        .iter()
+        .flat_map(|corner| {
+            [
+                Path::new(
+                    vec![corner.offset(-size, 0.0), corner.offset(size, 0.0)],
+                    style,
+                ),
+                Path::new(
+                    vec![corner.offset(0.0, -size), corner.offset(0.0, size)],
+                    style,
+                ),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        shapes::{path::Path, point::Point, rectangle::Rectangle},
+        svg::document::Document,
+    };
+
+    use super::Tiling;
+
+    #[test]
+    fn tiles_cover_document() {
+        let bounds = Rectangle::new(Point(0., 0.), 100., 50.);
+        let document = Document::new("tiles", bounds);
+        let tiling = Tiling {
+            overlap: 5.,
+            ..Tiling::new(2, 2)
+        };
+
+        let tiles = tiling.tiles(&document);
+
+        assert_eq!(tiles.len(), 4);
+        assert_eq!(tiles[0].bounds, Rectangle::new(Point(0., 0.), 55., 30.));
+        assert_eq!(tiles[3].bounds, Rectangle::new(Point(45., 20.), 55., 30.));
+    }
+
+    #[test]
+    fn tiles_clip_lines() {
+        let bounds = Rectangle::new(Point(0., 0.), 100., 100.);
+        let mut document = Document::new("tiles", bounds);
+        document.add_shape(Box::new(Path::new(
+            vec![Point(10., 10.), Point(90., 10.)],
+            Default::default(),
+        )));
+
+        let tiles = Tiling::new(2, 1).tiles(&document);
+
+        assert!(tiles[0].svg.contains("M10.00,10.00 H50.00"));
+        assert!(tiles[1].svg.contains("M50.00,10.00 H90.00"));
+    }
+
+    #[test]
+    fn sheet_count() {
+        let bounds = Rectangle::new(Point(0., 0.), 1000., 1400.);
+        let tiling = Tiling::for_sheet(&bounds, 420., 297., 10.).unwrap();
+
+        assert_eq!((tiling.columns, tiling.rows), (3, 6));
+        assert!(Tiling::for_sheet(&bounds, 420., 20., 10.).is_none());
+        assert!(Tiling::for_sheet(&bounds, 15., 297., 10.).is_none());
+    }
+
+    #[test]
+    fn manifest_escapes_name() {
+        let bounds = Rectangle::new(Point(0., 0.), 100., 100.);
+        let document = Document::new("say \"hi\" \\ bye", bounds);
+        let tiling = Tiling::new(1, 1);
+
+        let manifest = tiling.manifest(&document, &tiling.tiles(&document));
+
+        assert!(manifest.starts_with(r#"{"name":"say \"hi\" \\ bye","width":100"#));
+        assert_eq!(super::json_string("a\nb\u{1}"), r#""a\nb\u0001""#);
+    }
+
+    #[test]
+    fn registration_marks_inside_document() {
+        let bounds = Rectangle::new(Point(0., 0.), 100., 100.);
+        let document = Document::new("tiles", bounds);
+        let tiling = Tiling {
+            registration_marks: Some(5.),
+            ..Tiling::new(2, 2)
+        };
+
+        let svg = &tiling.tiles(&document)[0].svg;
+
+        assert_eq!(svg.matches("<path").count(), 8);
+        assert!(svg.ends_with("</svg>"));
+        assert_eq!(svg.matches("</svg>").count(), 1);
+    }
+}
@@ -0,0 +1,225 @@
+use std::ops::Range;
+
+use noise::{NoiseFn, Seedable, SuperSimplex};
+use rand::Rng;
+
+use crate::shapes::{circle::Circle, path::Path};
+
+use super::map::map;
+
+/// Describes how long each dash and each gap should be when dashing a [`Path`].
+#[derive(Debug, Clone)]
+pub enum DashPattern {
+    /// A repeating list of lengths, alternating between dash and gap,
+    /// just like `stroke-dasharray`.
+    Fixed(Vec<f64>),
+
+    /// Every dash and gap gets a random length in the given ranges.
+    Random {
+        /// Range of lengths for the dashes
+        dash: Range<f64>,
+
+        /// Range of lengths for the gaps
+        gap: Range<f64>,
+    },
+
+    /// Dash and gap lengths follow a noise field, sampled where each dash starts,
+    /// so neighboring lines get similar patterns.
+    Noise {
+        /// Range of lengths for the dashes
+        dash: Range<f64>,
+
+        /// Range of lengths for the gaps
+        gap: Range<f64>,
+
+        /// How smooth the noise field is, the higher the number the slower the pattern changes
+        smoothness: f64,
+
+        /// Seed for the noise field
+        seed: u32,
+    },
+}
+
+/**
+Split a path up into dashes following the given pattern. Each dash is a new [`Path`]
+with the same style as the original, so the dashes can be plotted as is instead of
+relying on `stroke-dasharray`. Curves are flattened, so the dashes are straight lines.
+
+Example
+```
+use generative_art::{
+    shapes::{path::Path, point::Point},
+    transforms::dash::{dash, DashPattern},
+};
+
+let path = Path::new(vec![Point(0.0, 0.0), Point(100.0, 0.0)], Default::default());
+let dashes = dash(&path, &DashPattern::Fixed(vec![10.0, 5.0]), &mut rand::thread_rng());
+
+assert_eq!(dashes.len(), 7);
+```
+*/
+pub fn dash<R: Rng>(path: &Path, pattern: &DashPattern, rng: &mut R) -> Vec<Path> {
+    // Dashes follow the curves of the path, which only has to be flattened once
+    let path = &path.flatten(0.1);
+    let length = path.length();
+    let noise = match pattern {
+        DashPattern::Noise { seed, .. } => Some(SuperSimplex::new().set_seed(*seed)),
+        _ => None,
+    };
+
+    let mut dashes = vec![];
+    let mut travelled = 0.0;
+    let mut step = 0;
+
+    while travelled < length {
+        let (dash_length, gap_length) = match pattern {
+            DashPattern::Fixed(lengths) if !lengths.is_empty() => (
+                lengths[step % lengths.len()],
+                lengths[(step + 1) % lengths.len()],
+            ),
+            DashPattern::Fixed(_) => (length, 0.0),
+            DashPattern::Random { dash, gap } => (sample(dash, rng), sample(gap, rng)),
+            DashPattern::Noise {
+                dash,
+                gap,
+                smoothness,
+                ..
+            } => {
+                let n = match (noise, path.point_at(travelled)) {
+                    (Some(noise), Some(point)) => {
+                        noise.get([point.0 / smoothness, point.1 / smoothness])
+                    }
+                    _ => 0.0,
+                };
+
+                (
+                    map(n, -1.0..1.0, dash.clone()),
+                    map(n, -1.0..1.0, gap.clone()),
+                )
+            }
+        };
+
+        if dash_length + gap_length <= 0.0 {
+            break;
+        }
+
+        if dash_length > 0.0 {
+            dashes.push(path.slice(travelled, (travelled + dash_length).min(length)));
+        }
+
+        travelled += dash_length + gap_length;
+        step += 2;
+    }
+
+    dashes
+        .into_iter()
+        .filter(|dash| dash.points.len() > 1)
+        .collect()
+}
+
+/**
+Turn a path into a trail of dots, {spacing} apart along the path, each with the given radius.
+The dots are filled with the stroke color of the path.
+*/
+pub fn stipple(path: &Path, spacing: f64, radius: f64) -> Vec<Circle> {
+    path.resample(spacing)
+        .points
+        .into_iter()
+        .map(|point| {
+            let mut circle = Circle::new(point, radius);
+            if let Some(color) = path.style.stroke {
+                circle.set_color(color);
+            }
+            circle
+        })
+        .collect()
+}
+
+fn sample<R: Rng>(range: &Range<f64>, rng: &mut R) -> f64 {
+    if range.is_empty() {
+        return range.start;
+    }
+
+    rng.gen_range(range.clone())
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+
+    use crate::shapes::{path::Path, point::Point, segment::Segment};
+
+    use super::{dash, stipple, DashPattern};
+
+    #[test]
+    fn fixed_dashes() {
+        let path = Path::new(
+            vec![Point(0., 0.), Point(10., 0.), Point(10., 10.)],
+            Default::default(),
+        );
+
+        let dashes = dash(&path, &DashPattern::Fixed(vec![6., 2.]), &mut thread_rng());
+
+        assert_eq!(dashes.len(), 3);
+        assert_eq!(dashes[0].points, vec![Point(0., 0.), Point(6., 0.)]);
+        assert_eq!(
+            dashes[1].points,
+            vec![Point(8., 0.), Point(10., 0.), Point(10., 4.)]
+        );
+        assert_eq!(dashes[2].points, vec![Point(10., 6.), Point(10., 10.)]);
+    }
+
+    #[test]
+    fn random_dashes_stay_on_path() {
+        let path = Path::new(vec![Point(0., 0.), Point(100., 0.)], Default::default());
+        let pattern = DashPattern::Random {
+            dash: 1.0..5.0,
+            gap: 1.0..5.0,
+        };
+
+        let dashes = dash(&path, &pattern, &mut thread_rng());
+
+        assert!(!dashes.is_empty());
+        assert!(dashes
+            .iter()
+            .flat_map(|dash| dash.points.iter())
+            .all(|point| point.1 == 0. && (0.0..=100.).contains(&point.0)));
+    }
+
+    #[test]
+    fn dashes_follow_curves() {
+        let mut path = Path::new(vec![Point(0., 0.)], Default::default());
+        path.add_segment(
+            Segment::Cubic(Point(0., 100.), Point(100., 100.)),
+            Point(100., 0.),
+        );
+
+        let dashes = dash(
+            &path,
+            &DashPattern::Fixed(vec![10., 10.]),
+            &mut thread_rng(),
+        );
+        assert_eq!(dashes.len(), (path.length() / 20.).ceil() as usize);
+
+        // Every point of a dash is on the curve, not on the line between its ends
+        let curve = path.flatten(0.01);
+        for point in dashes.iter().flat_map(|dash| dash.points.iter()) {
+            assert!(curve.distance_to_outline(point) < 0.2);
+        }
+        assert!(dashes[5].points.iter().all(|point| point.1 > 50.));
+
+        let dots = stipple(&path, 10., 1.);
+        assert!(dots
+            .iter()
+            .all(|dot| curve.distance_to_outline(&dot.center) < 0.2));
+    }
+
+    #[test]
+    fn stipple_dots() {
+        let path = Path::new(vec![Point(0., 0.), Point(10., 0.)], Default::default());
+        let dots = stipple(&path, 2.5, 0.5);
+
+        assert_eq!(dots.len(), 5);
+        assert_eq!(dots[1].center, Point(2.5, 0.));
+    }
+}
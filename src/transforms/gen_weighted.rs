@@ -17,7 +17,7 @@ let random_value = gen_weighted(0.0..1.0, &mut rng);
 ```
 */
 pub fn gen_weighted(range: Range<f64>, rng: &mut ThreadRng) -> f64 {
-    let a = rng.gen_range(0.0..1.0) as f64;
+    let a: f64 = rng.gen_range(0.0..1.0);
     let b = rng.gen_range(0.0..1.0);
 
     ((b - a).abs() * (1.0 + range.end - range.start) + range.start).floor()
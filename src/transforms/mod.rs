@@ -1,3 +1,6 @@
+/// Turn paths into dashes or dots that can be plotted
+pub mod dash;
+
 /// Generate a random number in a range, weighted towards the start of the range
 pub mod gen_weighted;
 